@@ -0,0 +1,132 @@
+use crate::paths::{create_private_dir, paths};
+use crate::GitHubEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// A directory of cached API responses, one JSON file per request.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+    not_found_ttl: Duration,
+}
+
+/// How long a "not found" answer is remembered unless configured otherwise.
+pub const NOT_FOUND_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Serialize, Deserialize)]
+struct NotFoundEntry {
+    fetched_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: DateTime<Utc>,
+    #[serde(default)]
+    etag: Option<String>,
+    events: Vec<GitHubEvent>,
+}
+
+/// A cached response, possibly older than the cache TTL.
+#[derive(Debug, Clone)]
+pub struct CachedEvents {
+    /// The cached events.
+    pub events: Vec<GitHubEvent>,
+    /// `ETag` of the response the events came from, for conditional requests.
+    pub etag: Option<String>,
+    /// Whether the entry is younger than the TTL and can be used as is.
+    pub fresh: bool,
+}
+
+impl Cache {
+    /// Creates a cache in `dir` whose entries stay fresh for `ttl`.
+    ///
+    /// Users and organizations that do not exist are remembered for
+    /// [`NOT_FOUND_TTL`], see [`Cache::with_not_found_ttl`].
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Cache { dir: dir.into(), ttl, not_found_ttl: NOT_FOUND_TTL }
+    }
+
+    /// Remembers "not found" answers for `ttl` instead, so repeated runs
+    /// with a mistyped name don't query the API again. Zero turns this off.
+    pub fn with_not_found_ttl(mut self, ttl: Duration) -> Self {
+        self.not_found_ttl = ttl;
+        self
+    }
+
+    /// The platform cache directory, e.g. `~/.cache/github-activity` on Linux,
+    /// see [`paths`](crate::paths::paths).
+    pub fn default_dir() -> Option<PathBuf> {
+        paths().cache_dir
+    }
+
+    /// The directory entries are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the entry stored under `key`, whether or not it is still fresh.
+    pub fn load(&self, key: &str) -> Option<CachedEvents> {
+        let contents = fs::read_to_string(self.path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+        let age = (Utc::now() - entry.fetched_at).to_std().unwrap_or_default();
+        Some(CachedEvents {
+            events: entry.events,
+            etag: entry.etag,
+            fresh: age <= self.ttl,
+        })
+    }
+
+    /// Stores `events` and their `etag` under `key`, stamped with the current
+    /// time.
+    pub fn store(&self, key: &str, events: &[GitHubEvent], etag: Option<&str>) -> io::Result<()> {
+        let entry = CacheEntry {
+            fetched_at: Utc::now(),
+            etag: etag.map(str::to_string),
+            events: events.to_vec(),
+        };
+        create_private_dir(&self.dir)?;
+        fs::write(self.path(key), serde_json::to_vec(&entry)?)
+    }
+
+    /// Whether a "not found" answer for `key` was stored within the
+    /// not-found TTL.
+    pub fn is_not_found(&self, key: &str) -> bool {
+        if self.not_found_ttl.is_zero() {
+            return false;
+        }
+        let Some(entry) = fs::read_to_string(self.not_found_path(key))
+            .ok()
+            .and_then(|contents| serde_json::from_str::<NotFoundEntry>(&contents).ok())
+        else {
+            return false;
+        };
+        let age = (Utc::now() - entry.fetched_at).to_std().unwrap_or_default();
+        age <= self.not_found_ttl
+    }
+
+    /// Records that `key` was not found, unless the not-found TTL is zero.
+    pub fn store_not_found(&self, key: &str) -> io::Result<()> {
+        if self.not_found_ttl.is_zero() {
+            return Ok(());
+        }
+        create_private_dir(&self.dir)?;
+        fs::write(self.not_found_path(key), serde_json::to_vec(&NotFoundEntry { fetched_at: Utc::now() })?)
+    }
+
+    fn not_found_path(&self, key: &str) -> PathBuf {
+        self.path(&format!("{}/not-found", key))
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
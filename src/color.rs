@@ -0,0 +1,123 @@
+use crate::GitHubEvent;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+
+/// How repository names are rendered as links.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkStyle {
+    /// Replace the name with its full URL.
+    Url,
+    /// Keep the name and wrap it in an OSC 8 terminal hyperlink.
+    Osc8,
+    /// Render a Markdown link, `[name](url)`.
+    Markdown,
+}
+
+/// ANSI styling for terminal output; a disabled style leaves text untouched.
+#[derive(Debug, Clone)]
+pub struct Style {
+    enabled: bool,
+    links: Option<(LinkStyle, String)>,
+    plain_repos: bool,
+}
+
+impl Style {
+    /// Creates a style that emits escape codes only when `enabled` is set.
+    pub fn new(enabled: bool) -> Self {
+        Style { enabled, links: None, plain_repos: false }
+    }
+
+    /// Renders repository names as links below `web_base`, e.g.
+    /// `https://github.com`.
+    pub fn with_links(mut self, style: LinkStyle, web_base: impl Into<String>) -> Self {
+        self.links = Some((style, web_base.into()));
+        self
+    }
+
+    /// Shows repository names without their owner, e.g. `developer-roadmap`
+    /// for `kamranahmedse/developer-roadmap`. Links still use the full name.
+    pub fn with_plain_repos(mut self) -> Self {
+        self.plain_repos = true;
+        self
+    }
+
+    /// Renders `text` in bold.
+    pub fn bold(&self, text: &str) -> String {
+        self.paint(text, BOLD)
+    }
+
+    /// Renders `text` dimmed, for secondary details like timestamps.
+    pub fn dim(&self, text: &str) -> String {
+        self.paint(text, DIM)
+    }
+
+    /// Renders a repository name in bold, as a link if enabled.
+    pub fn repo(&self, name: &str) -> String {
+        let shown = if self.plain_repos { plain_repo(name) } else { name };
+        let text = match &self.links {
+            Some((LinkStyle::Url, base)) => format!("{}/{}", base, name),
+            Some((LinkStyle::Osc8, base)) => {
+                format!("\x1b]8;;{}/{}\x1b\\{}\x1b]8;;\x1b\\", base, name, shown)
+            }
+            Some((LinkStyle::Markdown, base)) => format!("[{}]({}/{})", shown, base, name),
+            None => shown.to_string(),
+        };
+        self.bold(&text)
+    }
+
+    /// Colors `summary` by the type of `event` and renders its repository
+    /// name with [`Style::repo`].
+    pub fn event(&self, event: &GitHubEvent, summary: &str) -> String {
+        let color = if self.enabled {
+            event_color(&event.event_type).unwrap_or("")
+        } else {
+            ""
+        };
+        let body = match summary.rfind(&event.repo.name) {
+            Some(start) => {
+                let end = start + event.repo.name.len();
+                format!(
+                    "{}{}{}{}",
+                    &summary[..start],
+                    self.repo(&summary[start..end]),
+                    color,
+                    &summary[end..]
+                )
+            }
+            None => summary.to_string(),
+        };
+
+        if color.is_empty() {
+            body
+        } else {
+            format!("{}{}{}", color, body, RESET)
+        }
+    }
+
+    fn paint(&self, text: &str, code: &str) -> String {
+        if self.enabled {
+            format!("{}{}{}", code, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn event_color(event_type: &str) -> Option<&'static str> {
+    match event_type {
+        "PushEvent" => Some("\x1b[32m"),
+        "PullRequestEvent" | "PullRequestReviewEvent" | "PullRequestReviewCommentEvent" => Some("\x1b[34m"),
+        "IssuesEvent" | "IssueCommentEvent" => Some("\x1b[33m"),
+        "CreateEvent" | "ReleaseEvent" => Some("\x1b[36m"),
+        "DeleteEvent" => Some("\x1b[31m"),
+        "WatchEvent" | "ForkEvent" => Some("\x1b[35m"),
+        _ => None,
+    }
+}
+
+/// The repository part of an `owner/name` repository name.
+pub fn plain_repo(name: &str) -> &str {
+    name.rsplit_once('/').map_or(name, |(_, repo)| repo)
+}
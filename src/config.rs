@@ -0,0 +1,64 @@
+use crate::paths::paths;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default values for command-line flags, read from a TOML file.
+///
+/// Every key is optional. Values are kept as written so the CLI can validate
+/// them with the same parsers it uses for its flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Config {
+    /// Default for `--token`.
+    pub token: Option<String>,
+    /// Default for `--base-url`.
+    pub base_url: Option<String>,
+    /// Default for `--timezone`.
+    pub timezone: Option<String>,
+    /// Default for `--format`.
+    pub format: Option<String>,
+    /// Default for `--color`.
+    pub color: Option<String>,
+    /// Default for `--pages`.
+    pub pages: Option<u32>,
+    /// Default for `--absolute-time`.
+    pub absolute_time: Option<bool>,
+    /// Keys this version does not know about, so callers can warn about them.
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Errors returned while loading a [`Config`].
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The file exists but could not be read.
+    #[error("could not read {}: {}", .0.display(), .1)]
+    Read(PathBuf, #[source] io::Error),
+
+    /// The file is not valid TOML or a value has the wrong type.
+    #[error("invalid config file {}: {}", .0.display(), .1)]
+    Parse(PathBuf, #[source] toml::de::Error),
+}
+
+impl Config {
+    /// The platform config file, e.g. `~/.config/github-activity/config.toml`
+    /// on Linux, see [`paths`](crate::paths::paths).
+    pub fn default_path() -> Option<PathBuf> {
+        paths().config_file()
+    }
+
+    /// Reads the config at `path`. A missing file is an error unless
+    /// `optional` is set, in which case the empty config is returned.
+    pub fn load(path: &Path, optional: bool) -> Result<Config, ConfigError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if optional && e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(ConfigError::Read(path.to_path_buf(), e)),
+        };
+        toml::from_str(&contents).map_err(|e| ConfigError::Parse(path.to_path_buf(), e))
+    }
+}
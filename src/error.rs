@@ -0,0 +1,172 @@
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors returned while fetching activity from the GitHub API.
+#[derive(Debug, Error)]
+pub enum ActivityError {
+    /// The requested user does not exist.
+    #[error("User '{0}' not found")]
+    UserNotFound(String),
+
+    /// The requested organization does not exist.
+    #[error("Organization '{0}' not found")]
+    OrgNotFound(String),
+
+    /// The API rate limit has been exhausted.
+    #[error("API rate limit exceeded. {}", retry_hint(.reset))]
+    RateLimited {
+        /// When the rate limit window resets, from `X-RateLimit-Reset`.
+        reset: Option<DateTime<Utc>>,
+    },
+
+    /// The API refused the request for a reason other than the primary rate limit.
+    #[error("Access forbidden by the GitHub API (this may be a secondary rate limit)")]
+    Forbidden,
+
+    /// A secondary rate limit asked the client to wait before trying again.
+    #[error("Secondary rate limit exceeded; GitHub asked to retry after {}", seconds(.retry_after))]
+    SecondaryRateLimited {
+        /// How long to wait, from the `Retry-After` header.
+        retry_after: Duration,
+    },
+
+    /// The token was rejected.
+    #[error("Authentication failed: the provided token is invalid or expired")]
+    Unauthorized,
+
+    /// The API rejected a request parameter, e.g. a page beyond what it serves.
+    #[error("GitHub rejected the request as invalid (422 Unprocessable Entity){}", message_suffix(.0))]
+    Unprocessable(Option<String>),
+
+    /// The user or organization is blocked in this jurisdiction.
+    #[error("Activity for '{0}' is unavailable for legal reasons (451)")]
+    UnavailableForLegalReasons(String),
+
+    /// The request did not complete within the configured timeout.
+    #[error("Request timed out after {}", seconds(.0))]
+    Timeout(Duration),
+
+    /// The request could not be sent or the response could not be read.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
+    /// The response body could not be parsed as a list of events.
+    #[error("Could not parse the GitHub API response ({error}): {snippet}")]
+    MalformedResponse {
+        /// Why deserialization failed.
+        error: serde_json::Error,
+        /// The start of the response body, for diagnosis.
+        snippet: String,
+    },
+
+    /// The response body ended in the middle of the JSON, e.g. because the
+    /// connection dropped. Unlike [`ActivityError::MalformedResponse`] this is
+    /// retried.
+    #[error("The GitHub API response was cut off after {received} bytes ({error})")]
+    IncompleteResponse {
+        /// Where parsing ran out of input.
+        error: serde_json::Error,
+        /// Length of the body that did arrive.
+        received: usize,
+    },
+
+    /// The API answered with a status code we do not handle.
+    #[error("GitHub API request failed with status: {0}")]
+    Unexpected(StatusCode),
+}
+
+impl ActivityError {
+    /// How long the API asked to wait before retrying, if it did.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ActivityError::SecondaryRateLimited { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Whether a primary or secondary rate limit stopped the request.
+    pub fn is_rate_limit(&self) -> bool {
+        matches!(
+            self,
+            ActivityError::RateLimited { .. } | ActivityError::SecondaryRateLimited { .. }
+        )
+    }
+
+    /// Wraps a `reqwest` error, reporting timeouts as [`ActivityError::Timeout`].
+    pub(crate) fn from_reqwest(error: reqwest::Error, timeout: Duration) -> Self {
+        if error.is_timeout() {
+            ActivityError::Timeout(timeout)
+        } else {
+            ActivityError::Http(error)
+        }
+    }
+
+    /// Whether the failure is likely temporary and worth retrying.
+    ///
+    /// Server errors, network failures, truncated bodies and secondary rate
+    /// limits are transient; not-found, primary rate-limit and malformed
+    /// responses are deterministic and are not retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ActivityError::Timeout(_)
+            | ActivityError::SecondaryRateLimited { .. }
+            | ActivityError::IncompleteResponse { .. } => true,
+            ActivityError::Http(e) => e.is_connect() || e.is_timeout() || e.is_request() || e.is_body(),
+            ActivityError::Unexpected(status) => status.is_server_error(),
+            ActivityError::UserNotFound(_)
+            | ActivityError::OrgNotFound(_)
+            | ActivityError::RateLimited { .. }
+            | ActivityError::Forbidden
+            | ActivityError::Unauthorized
+            | ActivityError::Unprocessable(_)
+            | ActivityError::UnavailableForLegalReasons(_)
+            | ActivityError::MalformedResponse { .. } => false,
+        }
+    }
+}
+
+/// Number of characters of a malformed body kept in the error message.
+const SNIPPET_LEN: usize = 200;
+
+/// Cuts a response body down to a single-line prefix for error messages.
+pub(crate) fn snippet(body: &str) -> String {
+    let body = body.trim();
+    let mut snippet: String = body
+        .chars()
+        .take(SNIPPET_LEN)
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    if body.chars().count() > SNIPPET_LEN {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+fn retry_hint(reset: &Option<DateTime<Utc>>) -> String {
+    let Some(reset) = reset else {
+        return "Please try again later.".to_string();
+    };
+
+    let seconds = (*reset - Utc::now()).num_seconds().max(0);
+    let minutes = (seconds + 59) / 60;
+    format!(
+        "Please try again after {} UTC (in {} minute{}).",
+        reset.format("%H:%M:%S"),
+        minutes,
+        if minutes == 1 { "" } else { "s" }
+    )
+}
+
+fn message_suffix(message: &Option<String>) -> String {
+    message
+        .as_ref()
+        .map(|message| format!(": {}", message))
+        .unwrap_or_default()
+}
+
+fn seconds(duration: &Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{} second{}", secs, if secs == 1 { "" } else { "s" })
+}
@@ -0,0 +1,98 @@
+
+use crate::time::Zone;
+use crate::{is_force_push, GitHubEvent};
+use chrono::{Datelike, IsoWeek};
+use serde_json::Value;
+use std::cmp::Reverse;
+
+/// Buckets `events` by repository, busiest repository first.
+///
+/// Events keep their original order inside each group, and repositories
+/// with the same number of events keep the order they first appeared in.
+pub fn group_by_repo(events: &[GitHubEvent]) -> Vec<(&str, Vec<&GitHubEvent>)> {
+    let mut groups: Vec<(&str, Vec<&GitHubEvent>)> = Vec::new();
+    for event in events {
+        match groups.iter_mut().find(|(repo, _)| *repo == event.repo.name) {
+            Some((_, group)) => group.push(event),
+            None => groups.push((&event.repo.name, vec![event])),
+        }
+    }
+
+    groups.sort_by_key(|(_, group)| Reverse(group.len()));
+    groups
+}
+
+/// Buckets `events` by the ISO week they happened in, newest week first.
+///
+/// Week boundaries follow `zone`, so an event late on a Sunday evening in
+/// that zone stays in its week even if it is already Monday in UTC. Events
+/// keep their original order inside each group.
+pub fn group_by_week(events: &[GitHubEvent], zone: Zone) -> Vec<(IsoWeek, Vec<&GitHubEvent>)> {
+    let mut groups: Vec<(IsoWeek, Vec<&GitHubEvent>)> = Vec::new();
+    for event in events {
+        let week = zone.date(event.created_at).iso_week();
+        match groups.iter_mut().find(|(w, _)| *w == week) {
+            Some((_, group)) => group.push(event),
+            None => groups.push((week, vec![event])),
+        }
+    }
+
+    groups.sort_by_key(|(week, _)| Reverse(*week));
+    groups
+}
+
+/// Renders an ISO week as e.g. `2024-W18`.
+pub fn format_week(week: IsoWeek) -> String {
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+/// Merges runs of adjacent `PushEvent`s to the same branch of a repository
+/// into one.
+///
+/// The merged event keeps the first event of the run, with the commits of
+/// the whole run, the summed `size`, whether any push was `forced` and a
+/// `pushes` count written to its payload, which
+/// [`format_activity`](crate::format_activity) renders as "Pushed N
+/// commits across M pushes". Events are never reordered and
+/// other event types are left untouched.
+pub fn collapse_pushes(events: Vec<GitHubEvent>) -> Vec<GitHubEvent> {
+    let mut collapsed: Vec<GitHubEvent> = Vec::with_capacity(events.len());
+    for event in events {
+        match collapsed.last_mut() {
+            Some(last)
+                if event.event_type == "PushEvent"
+                    && last.event_type == "PushEvent"
+                    && last.repo.name == event.repo.name
+                    && last.payload.get("ref") == event.payload.get("ref") =>
+            {
+                merge_push(last, &event);
+            }
+            _ => collapsed.push(event),
+        }
+    }
+    collapsed
+}
+
+fn merge_push(into: &mut GitHubEvent, other: &GitHubEvent) {
+    let pushes = into.payload.get("pushes").and_then(Value::as_u64).unwrap_or(1);
+    let size = into.payload.get("size").and_then(Value::as_u64).unwrap_or(0)
+        + other.payload.get("size").and_then(Value::as_u64).unwrap_or(0);
+    let forced = is_force_push(into) || is_force_push(other);
+    let mut commits = into.payload.get("commits")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    commits.extend(
+        other.payload.get("commits")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default(),
+    );
+
+    if let Value::Object(payload) = &mut into.payload {
+        payload.insert("pushes".to_string(), Value::from(pushes + 1));
+        payload.insert("size".to_string(), Value::from(size));
+        payload.insert("forced".to_string(), Value::from(forced));
+        payload.insert("commits".to_string(), Value::Array(commits));
+    }
+}
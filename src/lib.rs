@@ -0,0 +1,1315 @@
+//! Fetch and summarize GitHub user activity.
+//!
+//! This crate powers the `github-activity` CLI but can be embedded on its
+//! own: [`fetch_user_activity`] retrieves a user's recent events and
+//! [`format_activity`] turns each one into a readable one-line summary.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn, Instrument};
+
+pub mod cache;
+pub mod color;
+pub mod config;
+pub mod error;
+pub mod group;
+pub mod paths;
+pub mod stats;
+pub mod template;
+pub mod time;
+pub mod username;
+
+pub use cache::{Cache, CachedEvents};
+pub use error::ActivityError;
+
+/// Default `User-Agent`, e.g. `github-activity-cli/0.1.0`. The API requires
+/// every request to carry one.
+pub const USER_AGENT: &str = concat!("github-activity-cli/", env!("CARGO_PKG_VERSION"));
+
+/// Number of events requested per page.
+pub const PER_PAGE: usize = 100;
+
+/// Number of pages of [`PER_PAGE`] events the events API serves: it only
+/// keeps the newest 300 events and answers 422 for later pages.
+pub const MAX_PAGES: u32 = 3;
+
+/// Base URL of the public GitHub REST API.
+pub const DEFAULT_BASE_URL: &str = "https://api.github.com";
+
+/// How long to wait after a `429 Too Many Requests` without `Retry-After`;
+/// GitHub's docs ask for at least a minute.
+const SECONDARY_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Derives the web UI base URL from an API base URL.
+///
+/// The public API maps to `https://github.com`; for GitHub Enterprise Server
+/// the `/api/v3` suffix is dropped, e.g. `https://github.example.com/api/v3`
+/// becomes `https://github.example.com`.
+pub fn web_base_url(api_base: &str) -> String {
+    let api_base = api_base.trim_end_matches('/');
+    if api_base == DEFAULT_BASE_URL {
+        "https://github.com".to_string()
+    } else {
+        api_base.trim_end_matches("/api/v3").to_string()
+    }
+}
+
+/// A single event from the GitHub Events API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubEvent {
+    /// Unique event id.
+    pub id: String,
+    /// Event type, e.g. `PushEvent` or `IssuesEvent`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// The user that triggered the event.
+    pub actor: Actor,
+    /// The repository the event happened in.
+    pub repo: Repository,
+    /// Raw event payload; its shape depends on `event_type`.
+    pub payload: serde_json::Value,
+    /// When the event was created.
+    pub created_at: DateTime<Utc>,
+    /// Whether the event is public; private events are only returned to
+    /// their owner.
+    #[serde(default = "default_public")]
+    pub public: bool,
+}
+
+fn default_public() -> bool {
+    true
+}
+
+/// The user that triggered an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Actor {
+    /// GitHub login of the user.
+    pub login: String,
+}
+
+/// The repository an event happened in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Repository {
+    /// Full repository name in `owner/name` form.
+    pub name: String,
+}
+
+/// Which events feed to fetch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Feed {
+    /// Events performed by a user (`/users/{name}/events`).
+    #[default]
+    User,
+    /// Public events across an organization's repositories
+    /// (`/orgs/{name}/events`).
+    Org,
+    /// Events of the people and repositories a user follows or watches
+    /// (`/users/{name}/received_events`). When authenticated as that user,
+    /// this includes events from private repositories they can see.
+    Received,
+    /// The public part of [`Feed::Received`]
+    /// (`/users/{name}/received_events/public`), so private events don't
+    /// take up room on pages when they would be hidden anyway.
+    ReceivedPublic,
+}
+
+impl Feed {
+    /// API path of the feed for `name`, relative to the base URL.
+    pub fn path(self, name: &str) -> String {
+        match self {
+            Feed::User => format!("/users/{}/events", name),
+            Feed::Org => format!("/orgs/{}/events", name),
+            Feed::Received => format!("/users/{}/received_events", name),
+            Feed::ReceivedPublic => format!("/users/{}/received_events/public", name),
+        }
+    }
+}
+
+/// Options controlling how activity is fetched.
+///
+/// The `Debug` output shows whether a token is set but never the token.
+#[derive(Clone)]
+pub struct FetchOptions {
+    /// Base URL of the API, e.g. `https://github.example.com/api/v3` for
+    /// GitHub Enterprise Server.
+    pub base_url: String,
+    /// Which feed to fetch.
+    pub feed: Feed,
+    /// Personal access token sent as a bearer token, if any.
+    pub token: Option<String>,
+    /// `User-Agent` header sent with each request; must not be empty.
+    pub user_agent: String,
+    /// Maximum number of pages of [`PER_PAGE`] events to request.
+    pub pages: u32,
+    /// Page to start at, so `first_page: 3, pages: 3` requests pages 3 to 5.
+    /// Pages before it are skipped. GitHub serves at most [`MAX_PAGES`].
+    pub first_page: u32,
+    /// How many times a transient failure is retried before giving up.
+    pub retries: u32,
+    /// Timeout applied to each HTTP request.
+    pub timeout: Duration,
+    /// HTTP client to send requests with, e.g. one configured with a proxy
+    /// or shared across fetches to reuse connections. A new client is built
+    /// for every fetch when unset.
+    pub client: Option<reqwest::Client>,
+    /// Disk cache consulted before, and updated after, each fetch.
+    pub cache: Option<Cache>,
+    /// Called before each page is requested.
+    pub progress: Option<Progress>,
+    /// Called after each HTTP request, retries included.
+    pub timing: Option<Timing>,
+    /// Also keep the events exactly as the API sent them, in
+    /// [`Activity::raw`]. The cache only holds parsed events, so it is
+    /// bypassed when this is set.
+    pub raw: bool,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        FetchOptions {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            feed: Feed::User,
+            token: None,
+            user_agent: USER_AGENT.to_string(),
+            pages: 1,
+            first_page: 1,
+            retries: 3,
+            timeout: Duration::from_secs(30),
+            client: None,
+            cache: None,
+            progress: None,
+            timing: None,
+            raw: false,
+        }
+    }
+}
+
+impl fmt::Debug for FetchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FetchOptions")
+            .field("base_url", &self.base_url)
+            .field("feed", &self.feed)
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .field("user_agent", &self.user_agent)
+            .field("pages", &self.pages)
+            .field("first_page", &self.first_page)
+            .field("retries", &self.retries)
+            .field("timeout", &self.timeout)
+            .field("client", &self.client)
+            .field("cache", &self.cache)
+            .field("progress", &self.progress)
+            .field("timing", &self.timing)
+            .field("raw", &self.raw)
+            .finish()
+    }
+}
+
+/// Callback reporting which page of whose events is being requested.
+///
+/// It is called with the username, the page number and the last page that
+/// may be requested, e.g. to drive a progress indicator.
+#[derive(Clone)]
+pub struct Progress(Arc<ProgressFn>);
+
+type ProgressFn = dyn Fn(&str, u32, u32) + Send + Sync;
+
+impl Progress {
+    /// Wraps `callback` so it can be set on [`FetchOptions`].
+    pub fn new(callback: impl Fn(&str, u32, u32) + Send + Sync + 'static) -> Self {
+        Progress(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Progress")
+    }
+}
+
+/// Callback reporting how long each HTTP request took.
+///
+/// It is called with the request URL and the time until its response (or
+/// error) arrived, e.g. to report where a run spent its time.
+#[derive(Clone)]
+pub struct Timing(Arc<TimingFn>);
+
+type TimingFn = dyn Fn(&str, Duration) + Send + Sync;
+
+impl Timing {
+    /// Wraps `callback` so it can be set on [`FetchOptions`].
+    pub fn new(callback: impl Fn(&str, Duration) + Send + Sync + 'static) -> Self {
+        Timing(Arc::new(callback))
+    }
+
+    fn record(options: &FetchOptions, url: &str, started: Instant) {
+        if let Some(timing) = &options.timing {
+            (timing.0)(url, started.elapsed());
+        }
+    }
+}
+
+impl fmt::Debug for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Timing")
+    }
+}
+
+/// The events of a user together with response metadata.
+#[derive(Debug, Clone)]
+pub struct Activity {
+    /// The fetched events, newest first.
+    pub events: Vec<GitHubEvent>,
+    /// Minimum polling interval requested by the API via `X-Poll-Interval`.
+    pub poll_interval: Option<Duration>,
+    /// The JSON of each event across all pages, byte for byte, only filled in
+    /// when [`FetchOptions::raw`] is set.
+    pub raw: Vec<Box<RawValue>>,
+    /// The current login when the API redirected the request because the
+    /// account was renamed.
+    pub renamed_to: Option<String>,
+    /// Number of events dropped because an earlier page already had them.
+    pub duplicates: usize,
+}
+
+impl Activity {
+    /// The entries of [`Activity::raw`], leaving out private events unless
+    /// `include_private` is set. The API returns those when the token
+    /// belongs to the requested user, so raw output would otherwise leak
+    /// activity in private repositories.
+    pub fn raw_events(&self, include_private: bool) -> Vec<&RawValue> {
+        self.raw
+            .iter()
+            .zip(&self.events)
+            .filter(|(_, event)| include_private || event.public)
+            .map(|(raw, _)| raw.as_ref())
+            .collect()
+    }
+}
+
+/// Fetches the public events of `username`, newest first.
+///
+/// This is a shorthand for [`fetch_activity`] that drops the response metadata.
+pub async fn fetch_user_activity(
+    username: &str,
+    options: &FetchOptions,
+) -> Result<Vec<GitHubEvent>, ActivityError> {
+    fetch_activity(username, options).await.map(|activity| activity.events)
+}
+
+/// Fetches the public events of `username` along with response metadata.
+///
+/// `username` names an organization instead when `options.feed` is
+/// [`Feed::Org`].
+///
+/// Up to `options.pages` pages of [`PER_PAGE`] events are requested, starting
+/// at `options.first_page` and stopping early once a page comes back short.
+/// When a token is set it is sent as a bearer token, raising the rate limit
+/// from 60 to 5000 requests per hour.
+/// Server errors and network failures are retried with exponential backoff,
+/// and secondary rate limits after the delay given in `Retry-After`.
+/// With a [`Cache`] configured, a fresh cached result skips the API entirely
+/// and a stale one is revalidated with its `ETag`; a `304 Not Modified`
+/// answer does not count against the rate limit.
+pub async fn fetch_activity(
+    username: &str,
+    options: &FetchOptions,
+) -> Result<Activity, ActivityError> {
+    let feed_key = format!("{}{}", options.base_url.trim_end_matches('/'), options.feed.path(username));
+    let first_page = options.first_page.max(1);
+    let last_page = first_page + options.pages.saturating_sub(1);
+    let cache_key = if first_page == 1 {
+        format!("{}/pages-{}", feed_key, options.pages)
+    } else {
+        format!("{}/pages-{}-{}", feed_key, first_page, last_page)
+    };
+    let cache = options.cache.as_ref().filter(|_| !options.raw);
+    let cached = cache.and_then(|cache| cache.load(&cache_key));
+    if let Some(cached) = &cached {
+        if cached.fresh {
+            return Ok(Activity {
+                events: cached.events.clone(),
+                poll_interval: None,
+                raw: Vec::new(),
+                renamed_to: None,
+                duplicates: 0,
+            });
+        }
+    }
+
+    if cache.is_some_and(|cache| cache.is_not_found(&feed_key)) {
+        debug!("{} was not found moments ago, skipping the request", username);
+        return Err(not_found(username, options.feed));
+    }
+
+    // Without a client of the caller's, every page shares one so its
+    // connections are reused.
+    let shared;
+    let options = match &options.client {
+        Some(_) => options,
+        None => {
+            shared = FetchOptions { client: Some(http_client(options)?), ..options.clone() };
+            &shared
+        }
+    };
+    let mut events = Vec::new();
+    let mut raw = Vec::new();
+    let mut etag = None;
+    let mut poll_interval = None;
+    let mut renamed_to = None;
+    let mut seen = HashSet::new();
+    let mut duplicates = 0;
+
+    for page in first_page..=last_page {
+        // Events are newest-first, so if the first page hasn't changed
+        // nothing has.
+        let if_none_match = match &cached {
+            Some(cached) if page == first_page => cached.etag.as_deref(),
+            _ => None,
+        };
+        if let Some(progress) = &options.progress {
+            (progress.0)(username, page, last_page);
+        }
+        let result = fetch_page_with_retry(username, options, page, if_none_match).await;
+        // A page past the last one the API serves ends the feed like a short
+        // page would, keeping the events fetched so far.
+        if page > first_page && matches!(result, Err(ActivityError::Unprocessable(_))) {
+            debug!("page {} of {} is beyond what the API serves", page, username);
+            break;
+        }
+        if let (Some(cache), Err(ActivityError::UserNotFound(_) | ActivityError::OrgNotFound(_))) = (cache, &result) {
+            if let Err(e) = cache.store_not_found(&feed_key) {
+                warn!("could not write cache in {}: {}", cache.dir().display(), e);
+            }
+        }
+        let (page_events, page_raw) = match result? {
+            Page::Events { events, raw: page_raw, etag: page_etag, poll_interval: page_poll_interval, redirected_to } => {
+                if page == first_page {
+                    etag = page_etag;
+                    poll_interval = page_poll_interval;
+                    renamed_to = redirected_to.and_then(|path| renamed_login(username, options.feed, &path, &events));
+                }
+                (events, page_raw)
+            }
+            Page::NotModified { poll_interval } => {
+                let cached = cached.ok_or(ActivityError::Unexpected(reqwest::StatusCode::NOT_MODIFIED))?;
+                store_in_cache(options, &cache_key, &cached.events, cached.etag.as_deref());
+                return Ok(Activity {
+                    events: cached.events,
+                    poll_interval,
+                    raw: Vec::new(),
+                    renamed_to: None,
+                    duplicates: 0,
+                });
+            }
+        };
+        // Events added while paging shift older ones onto the next page, so
+        // the same event can show up at the end of one page and the start
+        // of the next.
+        let page_len = page_events.len();
+        let mut page_raw = page_raw.into_iter();
+        for event in page_events {
+            let event_raw = page_raw.next();
+            if !seen.insert(event.id.clone()) {
+                duplicates += 1;
+                continue;
+            }
+            raw.extend(event_raw);
+            events.push(event);
+        }
+        if page_len < PER_PAGE {
+            break;
+        }
+    }
+
+    if !options.raw {
+        store_in_cache(options, &cache_key, &events, etag.as_deref());
+    }
+    Ok(Activity { events, poll_interval, raw, renamed_to, duplicates })
+}
+
+fn not_found(username: &str, feed: Feed) -> ActivityError {
+    match feed {
+        Feed::Org => ActivityError::OrgNotFound(username.to_string()),
+        Feed::User | Feed::Received | Feed::ReceivedPublic => ActivityError::UserNotFound(username.to_string()),
+    }
+}
+
+/// Works out the new login after a request for `username` was redirected
+/// to `path`.
+///
+/// The login is taken from the redirected path where it has one; redirects
+/// to an id-based path such as `/user/123/events` fall back to the actor of
+/// the user's own events.
+fn renamed_login(username: &str, feed: Feed, path: &str, events: &[GitHubEvent]) -> Option<String> {
+    path.split('/')
+        .skip_while(|segment| *segment != "users" && *segment != "orgs")
+        .nth(1)
+        .map(str::to_string)
+        .or_else(|| match feed {
+            Feed::User => events.first().map(|event| event.actor.login.clone()),
+            Feed::Org | Feed::Received | Feed::ReceivedPublic => None,
+        })
+        .filter(|login| !login.is_empty() && !login.eq_ignore_ascii_case(username))
+}
+
+/// Looks up the login of the user `options.token` belongs to.
+///
+/// The events API only includes private events when the token belongs to
+/// the user whose events are requested, so callers can check this first.
+pub async fn authenticated_user(options: &FetchOptions) -> Result<String, ActivityError> {
+    token_owner(options).await.map(|owner| owner.login)
+}
+
+/// The user a token belongs to and what it may access, see [`token_owner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenOwner {
+    /// Login of the user.
+    pub login: String,
+    /// Scopes of a classic token, from `X-OAuth-Scopes`. Fine-grained and
+    /// app tokens don't report scopes, so this is `None` for them.
+    pub scopes: Option<Vec<String>>,
+}
+
+impl TokenOwner {
+    /// Whether the token may read private repositories, which private
+    /// events require. Tokens that don't report scopes are given the
+    /// benefit of the doubt.
+    pub fn can_read_private_repos(&self) -> bool {
+        match &self.scopes {
+            Some(scopes) => scopes.iter().any(|scope| scope == "repo"),
+            None => true,
+        }
+    }
+}
+
+/// Looks up who `options.token` belongs to, along with its scopes.
+pub async fn token_owner(options: &FetchOptions) -> Result<TokenOwner, ActivityError> {
+    #[derive(Deserialize)]
+    struct User {
+        login: String,
+    }
+
+    let url = format!("{}/user", options.base_url.trim_end_matches('/'));
+    let response = get(options, &url, None).await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => {
+            let scopes = response.headers()
+                .get("x-oauth-scopes")
+                .and_then(|v| v.to_str().ok())
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|scope| !scope.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                });
+            let body = response
+                .text()
+                .await
+                .map_err(|e| ActivityError::from_reqwest(e, options.timeout))?;
+            serde_json::from_str::<User>(&body)
+                .map(|user| TokenOwner { login: user.login, scopes })
+                .map_err(|error| ActivityError::MalformedResponse {
+                    error,
+                    snippet: error::snippet(&body),
+                })
+        }
+        reqwest::StatusCode::UNAUTHORIZED => Err(ActivityError::Unauthorized),
+        status => Err(ActivityError::Unexpected(status)),
+    }
+}
+
+/// What the profile of an account with no events says about it, see
+/// [`account_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStatus {
+    /// The profile looks normal: the account is simply quiet.
+    Active,
+    /// The profile has `suspended_at` set. Only site admins of an
+    /// Enterprise server see this field.
+    Suspended,
+    /// The account is `ghost`, the placeholder GitHub shows for deleted users.
+    Ghost,
+    /// The events feed answered but the profile is missing, which is how
+    /// suspended and deleted accounts usually look.
+    Unavailable,
+}
+
+/// Looks up the profile of `username` to tell a quiet account from one that
+/// is suspended or deleted. Meant for when [`fetch_activity`] found no events,
+/// since an empty feed alone doesn't say which it is.
+pub async fn account_status(username: &str, options: &FetchOptions) -> Result<AccountStatus, ActivityError> {
+    #[derive(Deserialize)]
+    struct User {
+        login: String,
+        suspended_at: Option<String>,
+    }
+
+    let url = format!("{}/users/{}", options.base_url.trim_end_matches('/'), username);
+    let response = get(options, &url, None).await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => {
+            let body = response
+                .text()
+                .await
+                .map_err(|e| ActivityError::from_reqwest(e, options.timeout))?;
+            let user = serde_json::from_str::<User>(&body).map_err(|error| ActivityError::MalformedResponse {
+                error,
+                snippet: error::snippet(&body),
+            })?;
+            if user.suspended_at.is_some() {
+                Ok(AccountStatus::Suspended)
+            } else if user.login.eq_ignore_ascii_case("ghost") {
+                Ok(AccountStatus::Ghost)
+            } else {
+                Ok(AccountStatus::Active)
+            }
+        }
+        reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE => Ok(AccountStatus::Unavailable),
+        reqwest::StatusCode::UNAUTHORIZED => Err(ActivityError::Unauthorized),
+        status => Err(ActivityError::Unexpected(status)),
+    }
+}
+
+fn store_in_cache(options: &FetchOptions, key: &str, events: &[GitHubEvent], etag: Option<&str>) {
+    if let Some(cache) = &options.cache {
+        if let Err(e) = cache.store(key, events, etag) {
+            warn!("could not write cache in {}: {}", cache.dir().display(), e);
+        }
+    }
+}
+
+/// The URL of one page of `username`'s events.
+pub fn page_url(username: &str, options: &FetchOptions, page: u32) -> String {
+    format!(
+        "{}{}?per_page={}&page={}",
+        options.base_url.trim_end_matches('/'),
+        options.feed.path(username),
+        PER_PAGE,
+        page
+    )
+}
+
+/// A single page of the events API.
+enum Page {
+    Events {
+        events: Vec<GitHubEvent>,
+        /// The events as sent, when [`FetchOptions::raw`] is set.
+        raw: Vec<Box<RawValue>>,
+        /// Path the request ended up at, when the API redirected it.
+        redirected_to: Option<String>,
+        etag: Option<String>,
+        poll_interval: Option<Duration>,
+    },
+    /// The page matched the `If-None-Match` ETag (HTTP 304).
+    NotModified { poll_interval: Option<Duration> },
+}
+
+async fn fetch_page_with_retry(
+    username: &str,
+    options: &FetchOptions,
+    page: u32,
+    if_none_match: Option<&str>,
+) -> Result<Page, ActivityError> {
+    let mut attempt = 0;
+    loop {
+        match fetch_page(username, options, page, if_none_match).await {
+            Err(e) if e.is_transient() && attempt < options.retries => {
+                attempt += 1;
+                let delay = e.retry_after().unwrap_or_else(|| backoff_delay(attempt));
+                warn!(
+                    "Retrying in {}ms (attempt {}/{}): {}",
+                    delay.as_millis(),
+                    attempt,
+                    options.retries,
+                    e
+                );
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Exponential backoff starting at 500ms with up to 100% random jitter.
+fn backoff_delay(attempt: u32) -> Duration {
+    let base = 500u64 << (attempt - 1).min(6);
+    let jitter = RandomState::new().build_hasher().finish() % base;
+    Duration::from_millis(base + jitter)
+}
+
+/// The client requests are sent with: the caller's, or a new default one.
+fn http_client(options: &FetchOptions) -> Result<reqwest::Client, ActivityError> {
+    match &options.client {
+        Some(client) => Ok(client.clone()),
+        None => Ok(reqwest::Client::builder().build()?),
+    }
+}
+
+/// Sends a GET request for `url` with the configured timeout, `User-Agent`
+/// and token, reporting it to [`FetchOptions::timing`].
+async fn get(
+    options: &FetchOptions,
+    url: &str,
+    if_none_match: Option<&str>,
+) -> Result<reqwest::Response, ActivityError> {
+    let mut request = http_client(options)?
+        .get(url)
+        .timeout(options.timeout)
+        .header("User-Agent", &options.user_agent);
+    // The token only ever travels in the Authorization header, never in the URL.
+    if let Some(token) = &options.token {
+        request = request.bearer_auth(token);
+    }
+    if let Some(etag) = if_none_match {
+        request = request.header("If-None-Match", etag);
+    }
+    let started = Instant::now();
+    let response = request.send().await;
+    Timing::record(options, url, started);
+    response.map_err(|e| ActivityError::from_reqwest(e, options.timeout))
+}
+
+async fn fetch_page(
+    username: &str,
+    options: &FetchOptions,
+    page: u32,
+    if_none_match: Option<&str>,
+) -> Result<Page, ActivityError> {
+    let url = page_url(username, options, page);
+
+    let span = tracing::info_span!("request", username, page);
+    debug!(parent: &span, authenticated = options.token.is_some(), "GET {}", url);
+    let started = Instant::now();
+    let response = get(options, &url, if_none_match).instrument(span.clone()).await?;
+    info!(
+        parent: &span,
+        status = response.status().as_u16(),
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "response received"
+    );
+
+    warn_if_deprecated(&response);
+    let redirected_to = reqwest::Url::parse(&url)
+        .ok()
+        .filter(|requested| requested.path() != response.url().path())
+        .map(|_| response.url().path().to_string());
+
+    let poll_interval = header_value(&response, "x-poll-interval")
+        .and_then(|secs| u64::try_from(secs).ok())
+        .map(Duration::from_secs);
+
+    match response.status() {
+        reqwest::StatusCode::OK => {
+            let etag = response.headers()
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body = response
+                .text()
+                .await
+                .map_err(|e| ActivityError::from_reqwest(e, options.timeout))?;
+            let events = parse_events(&body)?;
+            let raw = if options.raw { parse_events(&body)? } else { Vec::new() };
+            Ok(Page::Events { events, raw, etag, poll_interval, redirected_to })
+        }
+        reqwest::StatusCode::NO_CONTENT => {
+            Ok(Page::Events { events: Vec::new(), raw: Vec::new(), etag: None, poll_interval, redirected_to })
+        }
+        reqwest::StatusCode::NOT_MODIFIED => Ok(Page::NotModified { poll_interval }),
+        reqwest::StatusCode::NOT_FOUND => Err(not_found(username, options.feed)),
+        reqwest::StatusCode::FORBIDDEN => {
+            if header_value(&response, "x-ratelimit-remaining") == Some(0) {
+                let reset = header_value(&response, "x-ratelimit-reset")
+                    .and_then(|ts| DateTime::from_timestamp(ts, 0));
+                Err(ActivityError::RateLimited { reset })
+            } else if let Some(secs) = header_value(&response, "retry-after") {
+                Err(ActivityError::SecondaryRateLimited {
+                    retry_after: Duration::from_secs(secs.max(0) as u64),
+                })
+            } else {
+                Err(ActivityError::Forbidden)
+            }
+        }
+        reqwest::StatusCode::TOO_MANY_REQUESTS => {
+            if header_value(&response, "x-ratelimit-remaining") == Some(0) {
+                let reset = header_value(&response, "x-ratelimit-reset")
+                    .and_then(|ts| DateTime::from_timestamp(ts, 0));
+                return Err(ActivityError::RateLimited { reset });
+            }
+            let retry_after = header_value(&response, "retry-after")
+                .map(|secs| Duration::from_secs(secs.max(0) as u64))
+                .unwrap_or(SECONDARY_RATE_LIMIT_WAIT);
+            Err(ActivityError::SecondaryRateLimited { retry_after })
+        }
+        reqwest::StatusCode::UNAUTHORIZED => Err(ActivityError::Unauthorized),
+        reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+            Err(ActivityError::Unprocessable(api_message(response).await))
+        }
+        reqwest::StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS => {
+            Err(ActivityError::UnavailableForLegalReasons(username.to_string()))
+        }
+        status => {
+            Err(ActivityError::Unexpected(status))
+        }
+    }
+}
+
+/// Reads the `message` of a GitHub error response, if it has one.
+async fn api_message(response: reqwest::Response) -> Option<String> {
+    let body: serde_json::Value = response.json().await.ok()?;
+    body.get("message")
+        .and_then(|m| m.as_str())
+        .filter(|m| !m.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Parses a page of events, treating an empty body as no activity.
+///
+/// A body that ends before the JSON does was cut off on the way and is
+/// reported as incomplete, so it can be retried; anything else that fails
+/// to parse is malformed.
+fn parse_events<T: serde::de::DeserializeOwned>(body: &str) -> Result<Vec<T>, ActivityError> {
+    if body.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(body).map_err(|error| {
+        if error.is_eof() {
+            ActivityError::IncompleteResponse { error, received: body.len() }
+        } else {
+            ActivityError::MalformedResponse {
+                error,
+                snippet: error::snippet(body),
+            }
+        }
+    })
+}
+
+/// Set once the deprecation warning has been shown, so it appears once per
+/// process rather than once per page or user.
+static DEPRECATION_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Warns when the API flags the endpoint with `Deprecation` or `Sunset`
+/// headers, meaning it is slated for removal and this crate may stop working.
+fn warn_if_deprecated(response: &reqwest::Response) {
+    let header = |name| response.headers().get(name).and_then(|v| v.to_str().ok());
+    let details: Vec<String> = [("Deprecation", header("deprecation")), ("Sunset", header("sunset"))]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{}: {}", name, value.trim())))
+        .collect();
+    if details.is_empty() || DEPRECATION_WARNED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    warn!(
+        "GitHub marked {} as deprecated ({}); this tool may stop working once it is removed",
+        response.url().path(),
+        details.join(", ")
+    );
+}
+
+fn header_value(response: &reqwest::Response, name: &str) -> Option<i64> {
+    response.headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Renders a one-line, human-readable summary of `event`.
+///
+/// Unknown event types fall back to a generic "Performed <type> in <repo>",
+/// with the payload's `action` (cut at 40 characters) in parentheses when it
+/// has one.
+pub fn format_activity(event: &GitHubEvent) -> String {
+    match event.event_type.as_str() {
+        "PushEvent" => {
+            let commits = event.payload.get("commits")
+                .and_then(|c| c.as_array())
+                .map(|c| c.len())
+                .unwrap_or(0);
+            let pushes = event.payload.get("pushes")
+                .and_then(|p| p.as_u64())
+                .unwrap_or(1);
+            let verb = if is_force_push(event) { "Force-pushed" } else { "Pushed" };
+            let across = if pushes > 1 {
+                format!(" across {} pushes", pushes)
+            } else {
+                String::new()
+            };
+            // Only branch refs are named; anything else is left out rather
+            // than shown raw.
+            let branch = event.payload.get("ref")
+                .and_then(|r| r.as_str())
+                .and_then(|r| r.strip_prefix("refs/heads/"))
+                .filter(|b| !b.is_empty())
+                .map(|b| format!(" {} in", b))
+                .unwrap_or_default();
+            format!("{} {} commit{}{} to{} {}", 
+                   verb, 
+                   commits, 
+                   if commits == 1 { "" } else { "s" }, 
+                   across, 
+                   branch, 
+                   event.repo.name)
+        }
+        "CreateEvent" => {
+            let ref_type = event.payload.get("ref_type")
+                .and_then(|r| r.as_str())
+                .unwrap_or("repository");
+            match ref_type {
+                "repository" => format!("Created repository {}", event.repo.name),
+                "branch" => {
+                    let branch = event.payload.get("ref")
+                        .and_then(|r| r.as_str())
+                        .unwrap_or("unknown");
+                    format!("Created branch '{}' in {}", branch, event.repo.name)
+                }
+                "tag" => {
+                    let tag = event.payload.get("ref")
+                        .and_then(|r| r.as_str())
+                        .unwrap_or("unknown");
+                    format!("Created tag '{}' in {}", tag, event.repo.name)
+                }
+                _ => format!("Created {} in {}", ref_type, event.repo.name)
+            }
+        }
+        "DeleteEvent" => {
+            let ref_type = event.payload.get("ref_type")
+                .and_then(|r| r.as_str())
+                .unwrap_or("branch");
+            let ref_name = event.payload.get("ref")
+                .and_then(|r| r.as_str())
+                .unwrap_or("unknown");
+            format!("Deleted {} '{}' in {}", ref_type, ref_name, event.repo.name)
+        }
+        "IssuesEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("updated");
+            let issue = event.payload.get("issue");
+            let issue_number = issue
+                .and_then(|i| i.get("number"))
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0);
+            format!("{} issue #{}{} in {}", 
+                   capitalize_first_letter(action), 
+                   issue_number, 
+                   title_suffix(issue), 
+                   event.repo.name)
+        }
+        "PullRequestEvent" => {
+            let pull_request = event.payload.get("pull_request");
+            let action = match event.payload.get("action").and_then(|a| a.as_str()) {
+                // A merge is reported as "closed", with the merge recorded on
+                // the pull request itself.
+                Some("closed") if is_merged(pull_request) => "merged",
+                Some(action) => action,
+                None => "updated",
+            };
+            let pr_number = event.payload.get("number")
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0);
+            format!("{} pull request #{}{} in {}", 
+                   capitalize_first_letter(action), 
+                   pr_number, 
+                   title_suffix(pull_request), 
+                   event.repo.name)
+        }
+        "WatchEvent" => {
+            format!("Starred {}", event.repo.name)
+        }
+        "ForkEvent" => {
+            match event.payload.get("forkee")
+                .and_then(|f| f.get("full_name"))
+                .and_then(|n| n.as_str()) {
+                Some(fork) => format!("Forked {} to {}", event.repo.name, fork),
+                None => format!("Forked {}", event.repo.name),
+            }
+        }
+        "ReleaseEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("published");
+            let release = event.payload.get("release");
+            let tag = release
+                .and_then(|r| r.get("tag_name"))
+                .and_then(|t| t.as_str())
+                .unwrap_or("unknown");
+            // The name is only worth showing when it says more than the tag.
+            let name = release
+                .and_then(|r| r.get("name"))
+                .and_then(|n| n.as_str())
+                .map(str::trim)
+                .filter(|n| !n.is_empty() && *n != tag)
+                .map(|n| format!(" '{}'", truncate(n, 60)))
+                .unwrap_or_default();
+            format!("{} release {}{}{} in {}", 
+                   capitalize_first_letter(action), 
+                   tag, 
+                   name, 
+                   release_details(release), 
+                   event.repo.name)
+        }
+        "PublicEvent" => {
+            format!("Made {} public", event.repo.name)
+        }
+        "MemberEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("added");
+            let member = event.payload.get("member")
+                .and_then(|m| m.get("login"))
+                .and_then(|l| l.as_str());
+            match (action, member) {
+                ("added", Some(member)) => format!("Added {} as collaborator to {}", member, event.repo.name),
+                ("added", None) => format!("Added a collaborator to {}", event.repo.name),
+                ("removed", Some(member)) => format!("Removed {} as collaborator from {}", member, event.repo.name),
+                ("removed", None) => format!("Removed a collaborator from {}", event.repo.name),
+                ("edited", Some(member)) => format!("Edited permissions of {} in {}", member, event.repo.name),
+                ("edited", None) => format!("Edited collaborator permissions in {}", event.repo.name),
+                (action, member) => format!("{} collaborator {}in {}", 
+                                          capitalize_first_letter(action), 
+                                          member.map(|m| format!("{} ", m)).unwrap_or_default(), 
+                                          event.repo.name),
+            }
+        }
+        "IssueCommentEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("created");
+            let issue_number = event.payload.get("issue")
+                .and_then(|i| i.get("number"))
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0);
+            format!("{} comment on issue #{} in {}", 
+                   capitalize_first_letter(action), 
+                   issue_number, 
+                   event.repo.name)
+        }
+        "PullRequestReviewEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("submitted");
+            let pr_number = event.payload.get("pull_request")
+                .and_then(|pr| pr.get("number"))
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0);
+            format!("{} review on pull request #{} in {}", 
+                   capitalize_first_letter(action), 
+                   pr_number, 
+                   event.repo.name)
+        }
+        "PullRequestReviewCommentEvent" => {
+            let pr_number = event.payload.get("pull_request")
+                .and_then(|pr| pr.get("number"))
+                .and_then(|n| n.as_u64())
+                .unwrap_or(0);
+            let path = event.payload.get("comment")
+                .and_then(|c| c.get("path"))
+                .and_then(|p| p.as_str());
+            match path {
+                Some(path) => format!("Commented on pull request #{} ({}) in {}", 
+                                      pr_number, 
+                                      path, 
+                                      event.repo.name),
+                None => format!("Commented on pull request #{} in {}", pr_number, event.repo.name),
+            }
+        }
+        "CommitCommentEvent" => {
+            let commit_id = event.payload.get("comment")
+                .and_then(|c| c.get("commit_id"))
+                .and_then(|id| id.as_str());
+            match commit_id {
+                Some(id) => format!("Commented on commit {} in {}", 
+                                    id.get(..7).unwrap_or(id), 
+                                    event.repo.name),
+                None => format!("Commented on a commit in {}", event.repo.name),
+            }
+        }
+        "GollumEvent" => {
+            let pages = event.payload.get("pages")
+                .and_then(|p| p.as_array())
+                .map(|p| p.as_slice())
+                .unwrap_or(&[]);
+            match pages {
+                [] => format!("Updated the wiki in {}", event.repo.name),
+                [page] => {
+                    let action = page.get("action")
+                        .and_then(|a| a.as_str())
+                        .unwrap_or("edited");
+                    match page.get("title").and_then(|t| t.as_str()) {
+                        Some(title) => format!("{} wiki page '{}' in {}", 
+                                              capitalize_first_letter(action), 
+                                              title, 
+                                              event.repo.name),
+                        None => format!("{} a wiki page in {}", 
+                                        capitalize_first_letter(action), 
+                                        event.repo.name),
+                    }
+                }
+                pages => format!("Edited {} wiki pages in {}", pages.len(), event.repo.name),
+            }
+        }
+        "SponsorshipEvent" => {
+            let action = event.payload.get("action")
+                .and_then(|a| a.as_str())
+                .unwrap_or("created");
+            let sponsorable = event.payload.get("sponsorship")
+                .and_then(|s| s.get("sponsorable"))
+                .and_then(|s| s.get("login"))
+                .and_then(|l| l.as_str())
+                .unwrap_or(&event.repo.name);
+            match action {
+                "created" => format!("Sponsored {}", sponsorable),
+                "cancelled" => format!("Cancelled sponsorship of {}", sponsorable),
+                "tier_changed" => format!("Changed sponsorship tier for {}", sponsorable),
+                "pending_cancellation" => format!("Scheduled cancellation of sponsorship of {}", sponsorable),
+                "pending_tier_change" => format!("Scheduled sponsorship tier change for {}", sponsorable),
+                _ => format!("Updated sponsorship of {}", sponsorable),
+            }
+        }
+        "StatusEvent" => {
+            let state = event.payload.get("state")
+                .and_then(|s| s.as_str())
+                .unwrap_or("updated");
+            let context = event.payload.get("context")
+                .and_then(|c| c.as_str())
+                .map(|c| format!(" ({})", c))
+                .unwrap_or_default();
+            match event.payload.get("sha").and_then(|s| s.as_str()) {
+                Some(sha) => format!("Reported {} status{} for commit {} in {}", 
+                                     state, 
+                                     context, 
+                                     sha.get(..7).unwrap_or(sha), 
+                                     event.repo.name),
+                None => format!("Reported {} status{} in {}", state, context, event.repo.name),
+            }
+        }
+        _ => {
+            let action = event.payload
+                .get("action")
+                .and_then(|a| a.as_str())
+                .map(str::trim)
+                .filter(|a| !a.is_empty());
+            match action {
+                Some(action) => format!(
+                    "Performed {} ({}) in {}", 
+                    event.event_type, 
+                    truncate(action, 40), 
+                    event.repo.name
+                ),
+                None => format!("Performed {} in {}", event.event_type, event.repo.name),
+            }
+        }
+    }
+}
+
+/// Whether a `PushEvent` rewrote history: either the payload says so, or
+/// none of the pushed commits are new to the repository.
+pub(crate) fn is_force_push(event: &GitHubEvent) -> bool {
+    if let Some(forced) = event.payload.get("forced").and_then(|f| f.as_bool()) {
+        return forced;
+    }
+    let size = event.payload.get("size").and_then(|s| s.as_u64());
+    let distinct_size = event.payload.get("distinct_size").and_then(|s| s.as_u64());
+    matches!((size, distinct_size), (Some(size), Some(0)) if size > 0)
+}
+
+/// Maximum number of commits listed by [`commit_summaries`].
+const MAX_LISTED_COMMITS: usize = 10;
+
+/// Lists the commits of a `PushEvent` as "<short sha> <first line>".
+///
+/// Messages are cut at the first newline and at 72 characters. At most ten
+/// commits are listed, followed by an "... and N more" line. Other event
+/// types have no commit details and return an empty list.
+pub fn commit_summaries(event: &GitHubEvent) -> Vec<String> {
+    if event.event_type != "PushEvent" {
+        return Vec::new();
+    }
+
+    let commits = event.payload.get("commits")
+        .and_then(|c| c.as_array())
+        .map(|c| c.as_slice())
+        .unwrap_or(&[]);
+
+    let mut lines: Vec<String> = commits
+        .iter()
+        .take(MAX_LISTED_COMMITS)
+        .map(|commit| {
+            let sha = commit.get("sha")
+                .and_then(|s| s.as_str())
+                .unwrap_or("unknown");
+            let message = commit.get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("")
+                .lines()
+                .next()
+                .unwrap_or("");
+            format!("{} {}", sha.get(..7).unwrap_or(sha), truncate(message, 72))
+        })
+        .collect();
+
+    if commits.len() > MAX_LISTED_COMMITS {
+        lines.push(format!("... and {} more", commits.len() - MAX_LISTED_COMMITS));
+    }
+    lines
+}
+
+/// The full SHAs of the commits in a `PushEvent`, oldest first.
+///
+/// Unlike [`commit_summaries`] nothing is shortened or left out, and commits
+/// without a SHA are skipped. Other event types return an empty list.
+pub fn commit_shas(event: &GitHubEvent) -> Vec<&str> {
+    if event.event_type != "PushEvent" {
+        return Vec::new();
+    }
+
+    event.payload.get("commits")
+        .and_then(|c| c.as_array())
+        .map(|commits| {
+            commits
+                .iter()
+                .filter_map(|commit| commit.get("sha").and_then(|s| s.as_str()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Links to the resource an event is about on the web UI below `web_base`.
+///
+/// The `html_url` from the payload is preferred (the comment, issue, pull
+/// request, release, fork or wiki page), then a URL built from the payload
+/// (the compared commits of a push, the created branch or tag), and finally
+/// the repository itself. Returns `None` when the event names no repository.
+pub fn event_url(event: &GitHubEvent, web_base: &str) -> Option<String> {
+    if event.repo.name.is_empty() {
+        return None;
+    }
+    let payload = &event.payload;
+    let html_url = |path: &[&str]| {
+        path.iter()
+            .try_fold(payload, |value, key| value.get(*key))
+            .and_then(|url| url.as_str())
+            .map(str::to_string)
+    };
+    let repo_url = format!("{}/{}", web_base.trim_end_matches('/'), event.repo.name);
+
+    let url = match event.event_type.as_str() {
+        "IssuesEvent" => html_url(&["issue", "html_url"]),
+        "IssueCommentEvent" => html_url(&["comment", "html_url"])
+            .or_else(|| html_url(&["issue", "html_url"])),
+        "PullRequestEvent" => html_url(&["pull_request", "html_url"]),
+        "PullRequestReviewEvent" => html_url(&["review", "html_url"])
+            .or_else(|| html_url(&["pull_request", "html_url"])),
+        "PullRequestReviewCommentEvent" => html_url(&["comment", "html_url"])
+            .or_else(|| html_url(&["pull_request", "html_url"])),
+        "CommitCommentEvent" => html_url(&["comment", "html_url"]),
+        "ReleaseEvent" => html_url(&["release", "html_url"]),
+        "ForkEvent" => html_url(&["forkee", "html_url"]),
+        "GollumEvent" => payload.get("pages")
+            .and_then(|p| p.get(0))
+            .and_then(|p| p.get("html_url"))
+            .and_then(|url| url.as_str())
+            .map(str::to_string),
+        "PushEvent" => {
+            let before = payload.get("before").and_then(|b| b.as_str());
+            let head = payload.get("head").and_then(|h| h.as_str());
+            match (before, head) {
+                (Some(before), Some(head)) => Some(format!(
+                    "{}/compare/{}...{}", 
+                    repo_url, 
+                    before.get(..12).unwrap_or(before), 
+                    head.get(..12).unwrap_or(head)
+                )),
+                (None, Some(head)) => Some(format!("{}/commit/{}", repo_url, head)),
+                _ => None,
+            }
+        }
+        "CreateEvent" => {
+            let ref_type = payload.get("ref_type").and_then(|r| r.as_str());
+            let ref_name = payload.get("ref").and_then(|r| r.as_str());
+            match (ref_type, ref_name) {
+                (Some("branch"), Some(name)) => Some(format!("{}/tree/{}", repo_url, name)),
+                (Some("tag"), Some(name)) => Some(format!("{}/releases/tag/{}", repo_url, name)),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    Some(url.unwrap_or(repo_url))
+}
+
+/// Renders " (prerelease, 3 assets)" from a release payload's `draft` and
+/// `prerelease` flags and its asset count, or nothing when none apply.
+fn release_details(release: Option<&serde_json::Value>) -> String {
+    let Some(release) = release else {
+        return String::new();
+    };
+    let flag = |name| release.get(name).and_then(|f| f.as_bool()).unwrap_or(false);
+    let mut details = Vec::new();
+    if flag("draft") {
+        details.push("draft".to_string());
+    }
+    if flag("prerelease") {
+        details.push("prerelease".to_string());
+    }
+    let assets = release.get("assets").and_then(|a| a.as_array()).map_or(0, |a| a.len());
+    if assets > 0 {
+        details.push(format!("{} asset{}", assets, if assets == 1 { "" } else { "s" }));
+    }
+    if details.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", details.join(", "))
+    }
+}
+
+/// Whether a pull request payload was merged, going by `merged` or, where
+/// that is missing, a `merged_at` timestamp.
+fn is_merged(pull_request: Option<&serde_json::Value>) -> bool {
+    let Some(pull_request) = pull_request else {
+        return false;
+    };
+    match pull_request.get("merged").and_then(|m| m.as_bool()) {
+        Some(merged) => merged,
+        None => pull_request.get("merged_at").is_some_and(|m| m.is_string()),
+    }
+}
+
+/// Renders ": <title>" for an issue or pull request payload, or nothing
+/// when it has no title. Titles are cut at 60 characters.
+fn title_suffix(item: Option<&serde_json::Value>) -> String {
+    item.and_then(|i| i.get("title"))
+        .and_then(|t| t.as_str())
+        .filter(|t| !t.trim().is_empty())
+        .map(|t| format!(": {}", truncate(t.trim(), 60)))
+        .unwrap_or_default()
+}
+
+/// Shortens `s` to at most `max` characters, ending in "..." when cut.
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let kept: String = s.chars().take(max.saturating_sub(3)).collect();
+        kept + "..."
+    }
+}
+
+fn capitalize_first_letter(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    }
+}
@@ -1,222 +1,1872 @@
-use anyhow::{anyhow, Result};
-use chrono::{DateTime, Utc};
-use clap::Parser;
-use reqwest;
-use serde::Deserialize;
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use clap_complete::Shell;
+use futures::future::join_all;
+use github_activity::cache::NOT_FOUND_TTL;
+use github_activity::color::{plain_repo, LinkStyle, Style};
+use github_activity::config::Config;
+use github_activity::group::{collapse_pushes, format_week, group_by_repo, group_by_week};
+use github_activity::stats::{activity_stats, count_by_day, count_by_type, push_commits, total_commits};
+use github_activity::template::{Field, Template};
+use github_activity::time::{absolute_time, humanize_time, parse_age, parse_date_bound, Zone};
+use github_activity::username::normalize_username;
+use github_activity::{
+    account_status, commit_shas, commit_summaries, event_url, fetch_activity, format_activity, page_url,
+    token_owner, web_base_url, AccountStatus, Activity, ActivityError, Cache, Feed, FetchOptions, GitHubEvent,
+    Progress, Timing, DEFAULT_BASE_URL, MAX_PAGES, USER_AGENT,
+};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::prelude::*;
+
+#[cfg(feature = "tui")]
+mod tui;
 
 #[derive(Parser)]
-#[command(name = "github-activity")]
+#[command(name = "github-activity", version)]
 #[command(about = "A CLI tool to fetch GitHub user activity")]
 struct Cli {
-    /// GitHub username to fetch activity for
-    username: String,
+    /// GitHub usernames to fetch activity for (organization names with --org); `-` reads them from stdin
+    #[arg(
+        required_unless_present_any = ["from_file", "generate_completions", "generate_man"],
+        value_parser = parse_username
+    )]
+    usernames: Vec<String>,
+
+    /// Read usernames from a file, one per line (blank lines and # comments are skipped)
+    #[arg(long, value_name = "PATH")]
+    from_file: Option<PathBuf>,
+
+    /// Personal access token for authenticated requests
+    #[arg(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+
+    /// Base URL of the GitHub API (for GitHub Enterprise Server)
+    #[arg(long, env = "GITHUB_API_URL", default_value = DEFAULT_BASE_URL, value_parser = parse_base_url)]
+    base_url: String,
+
+    /// Treat the names as organizations and fetch their public events
+    #[arg(long)]
+    org: bool,
+
+    /// Fetch the events a user receives in their feed instead of their own events
+    #[arg(long, conflicts_with = "org")]
+    received: bool,
+
+    /// Include private events; the token must belong to the requested user
+    #[arg(long, conflicts_with = "org")]
+    include_private: bool,
+
+    /// Number of pages of events to fetch (100 events per page, max 3)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=MAX_PAGES as i64))]
+    pages: u32,
+
+    /// First page to fetch, skipping newer events (1-3; up to --page-end or page 3)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..=MAX_PAGES as i64), conflicts_with = "pages")]
+    page_start: Option<u32>,
+
+    /// Last page to fetch (1-3; from --page-start or page 1)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..=MAX_PAGES as i64), conflicts_with = "pages")]
+    page_end: Option<u32>,
+
+    /// Maximum number of events to display
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Only show events of this type (repeatable, e.g. PushEvent or push)
+    #[arg(long = "type", visible_alias = "include-type", value_name = "EVENT_TYPE", value_parser = parse_event_type)]
+    event_types: Vec<String>,
+
+    /// Hide events of this type (repeatable); wins over --type for the same type
+    #[arg(long = "exclude-type", value_name = "EVENT_TYPE", value_parser = parse_event_type)]
+    excluded_types: Vec<String>,
+
+    /// Number of times to retry transient failures (5xx, network errors)
+    #[arg(long, default_value_t = 3)]
+    retries: u32,
+
+    /// User-Agent header to identify requests with
+    #[arg(long, value_name = "STR", default_value = USER_AGENT, value_parser = parse_user_agent)]
+    user_agent: String,
+
+    /// Maximum number of users fetched at the same time
+    #[arg(long, value_name = "N", default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    concurrency: u32,
+
+    /// HTTP request timeout in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = 30)]
+    timeout: u64,
+
+    /// Send all requests through this proxy (credentials may be given as user:pass@host)
+    #[arg(long, value_name = "URL", value_parser = parse_proxy)]
+    proxy: Option<reqwest::Proxy>,
+
+    /// Seconds a cached response stays fresh
+    #[arg(long, value_name = "SECONDS", default_value_t = 300)]
+    cache_ttl: u64,
+
+    /// Keep cached responses in this directory instead of the platform cache directory
+    #[arg(long, value_name = "PATH")]
+    cache_dir: Option<PathBuf>,
+
+    /// Always query the API instead of using the disk cache
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Don't remember for a minute that a user or organization was not found
+    #[arg(long)]
+    no_negative_cache: bool,
+
+    /// Show full timestamps instead of relative times like "3 hours ago"
+    #[arg(long)]
+    absolute_time: bool,
+
+    /// Show times in this zone: an IANA name like America/New_York, UTC or local
+    #[arg(long, value_name = "TZ", default_value = "UTC", value_parser = Zone::parse)]
+    timezone: Zone,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Group text output under a header per bucket
+    #[arg(long, value_enum)]
+    group_by: Option<GroupBy>,
+
+    /// Only show events on or after this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[arg(long, value_name = "DATE", value_parser = parse_since)]
+    since: Option<DateTime<Utc>>,
+
+    /// Only show events newer than this age (e.g. 30m, 6h, 7d, 2w)
+    #[arg(long, value_name = "AGE", value_parser = parse_age, conflicts_with = "since")]
+    max_age: Option<TimeDelta>,
+
+    /// Only show events on or before this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[arg(long, value_name = "DATE", value_parser = parse_until)]
+    until: Option<DateTime<Utc>>,
+
+    /// Hide pushes with fewer than N commits; other event types are kept
+    #[arg(long, value_name = "N")]
+    min_commits: Option<u64>,
+
+    /// Merge consecutive pushes to the same repository into one line
+    #[arg(long)]
+    collapse: bool,
+
+    /// Show events oldest first (applied before --limit)
+    #[arg(long)]
+    reverse: bool,
+
+    /// List the commits of each push underneath it
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Exit with status 2 when a user has no activity matching the filters
+    #[arg(long, conflicts_with = "watch")]
+    fail_on_empty: bool,
+
+    /// Format each event line with placeholders: {type}, {repo}, {actor}, {time}, {summary}, {url}, {id}
+    #[arg(long, value_name = "STR", value_parser = Template::parse)]
+    template: Option<Template>,
+
+    /// Print only the event lines: no headers, notes or progress spinner
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Only show events in this repository (owner/name or just name)
+    #[arg(long)]
+    repo: Option<String>,
+
+    /// Only show events in repositories whose name starts with this, e.g. `myorg/`
+    #[arg(long, value_name = "STR")]
+    repo_prefix: Option<String>,
+
+    /// Hide events by bot accounts such as dependabot[bot]
+    #[arg(long)]
+    no_bots: bool,
+
+    /// Only show events by this login, mainly for --org and --received feeds (repeatable)
+    #[arg(long = "actor", visible_alias = "actor-filter", value_name = "LOGIN", value_parser = parse_actor)]
+    actors: Vec<String>,
+
+    /// Print a count of events by type after the activity
+    #[arg(long)]
+    summary: bool,
+
+    /// Print how many events each repository had, busiest first, instead of the events
+    #[arg(long, conflicts_with_all = ["format", "digest", "watch", "group_by", "json_raw", "count_only", "shas"])]
+    count_by_repo: bool,
+
+    /// Print one line per user with repository and event counts instead of the events
+    #[arg(long, conflicts_with_all = ["watch", "format"])]
+    digest: bool,
+
+    /// Print the total number of commits pushed after the activity
+    #[arg(long)]
+    commits: bool,
+
+    /// Print the busiest day, most active repository, commits pushed and current streak
+    #[arg(long)]
+    stats: bool,
+
+    /// Print each event type's count and most recent occurrence after the events
+    #[arg(long)]
+    footer: bool,
+
+    /// Print a heatmap of the last two weeks of activity after the events
+    #[arg(long)]
+    calendar: bool,
+
+    /// Keep running and print new events as they appear
+    #[arg(long)]
+    watch: bool,
+
+    /// Seconds between polls in --watch mode
+    #[arg(long, value_name = "SECONDS", default_value_t = 60, requires = "watch")]
+    interval: u64,
+
+    /// Open the user's profile (or the --repo repository) in the browser after printing
+    #[arg(long, conflicts_with = "watch")]
+    open: bool,
+
+    /// Print how long each API request took, and how many were made, to stderr
+    #[arg(long, conflicts_with = "watch")]
+    timing: bool,
+
+    /// Log verbosity on stderr (off, error, warn, info, debug, trace)
+    #[arg(long, value_name = "LEVEL", default_value = "warn")]
+    log_level: LevelFilter,
+
+    /// Indent --format json output for reading instead of printing it on one line
+    #[arg(long, visible_alias = "json-pretty")]
+    prettify_json: bool,
+
+    /// Print only the number of events matching the filters, summed over all users
+    #[arg(long, conflicts_with_all = ["format", "digest", "watch", "group_by", "json_raw"])]
+    count_only: bool,
+
+    /// Print "<repo> <sha>" for every commit of the matching pushes instead of summaries
+    #[arg(long, conflicts_with_all = ["format", "digest", "watch", "group_by", "json_raw", "count_only"])]
+    shas: bool,
+
+    /// Print the events exactly as the API returned them, as one JSON array; filters are not applied
+    #[arg(long, conflicts_with_all = ["format", "digest", "watch", "group_by"])]
+    json_raw: bool,
+
+    /// Write the output to this file instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Render repository names as links to github.com
+    #[arg(long)]
+    links: bool,
+
+    /// Show repository names without their owner in text output; JSON and CSV keep the full name
+    #[arg(long)]
+    plain_repo: bool,
+
+    /// When to color text output
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Read default flag values from this TOML file instead of the default location
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Print the requests and filters that would be used, without calling the API
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Browse the events in an interactive, scrollable list
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with_all = ["watch", "output", "format", "digest", "timing", "json_raw", "count_only", "shas", "count_by_repo"])]
+    tui: bool,
+
+    /// Print a shell completion script to stdout and exit
+    #[arg(long, value_name = "SHELL", conflicts_with_all = ["usernames", "from_file"])]
+    generate_completions: Option<Shell>,
+
+    /// Print a roff man page to stdout and exit
+    #[arg(long, conflicts_with_all = ["usernames", "from_file", "generate_completions"])]
+    generate_man: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable list
+    Text,
+    /// JSON array of events
+    Json,
+    /// One JSON object per line, for streaming
+    Jsonl,
+    /// CSV with a header row
+    Csv,
+    /// Markdown list grouped by day, with linked repositories
+    Markdown,
+    /// Aligned columns fitted to the terminal width
+    Table,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorChoice {
+    /// Color when stdout is a terminal
+    Auto,
+    /// Always emit color codes
+    Always,
+    /// Never emit color codes
+    Never,
+}
+
+impl ColorChoice {
+    fn enabled(self, to_file: bool) -> bool {
+        match self {
+            ColorChoice::Auto => !to_file && io::stdout().is_terminal() && !no_color() && !dumb_terminal(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// Whether `NO_COLOR` is set to a non-empty value, see <https://no-color.org>.
+/// It only affects `--color auto`; `--color always` still colors.
+fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Whether `TERM=dumb` says the terminal doesn't understand escape codes,
+/// so colors, hyperlinks and redrawn spinner lines are left out.
+fn dumb_terminal() -> bool {
+    std::env::var_os("TERM").is_some_and(|term| term == "dumb")
 }
 
-#[derive(Debug, Deserialize)]
-struct GitHubEvent {
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GroupBy {
+    /// One section per repository, busiest first
+    Repo,
+    /// One section per ISO week in --timezone, newest first
+    Week,
+}
+
+const EVENT_TYPES: &[&str] = &[
+    "PushEvent",
+    "CreateEvent",
+    "DeleteEvent",
+    "IssuesEvent",
+    "PullRequestEvent",
+    "WatchEvent",
+    "ForkEvent",
+    "ReleaseEvent",
+    "PublicEvent",
+    "MemberEvent",
+    "IssueCommentEvent",
+    "PullRequestReviewEvent",
+    "GollumEvent",
+    "CommitCommentEvent",
+    "PullRequestReviewCommentEvent",
+    "SponsorshipEvent",
+    "StatusEvent",
+];
+
+const EVENT_TYPE_ALIASES: &[(&str, &str)] = &[
+    ("issue", "IssuesEvent"),
+    ("pr", "PullRequestEvent"),
+    ("star", "WatchEvent"),
+    ("comment", "IssueCommentEvent"),
+    ("review", "PullRequestReviewEvent"),
+    ("wiki", "GollumEvent"),
+];
+
+/// Cleaned-up view of an event used for machine-readable output.
+#[derive(Debug, Serialize)]
+struct EventOutput<'a> {
     #[serde(rename = "type")]
-    event_type: String,
-    actor: Actor,
-    repo: Repository,
-    payload: serde_json::Value,
+    event_type: &'a str,
+    repo: &'a str,
+    actor: &'a str,
     created_at: DateTime<Utc>,
+    summary: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct Actor {
-    login: String,
+impl<'a> From<&'a GitHubEvent> for EventOutput<'a> {
+    fn from(event: &'a GitHubEvent) -> Self {
+        EventOutput {
+            event_type: &event.event_type,
+            repo: &event.repo.name,
+            actor: &event.actor.login,
+            created_at: event.created_at,
+            summary: format_activity(event),
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct Repository {
-    name: String,
+/// JSON view of an event, with a link to what it is about.
+#[derive(Debug, Serialize)]
+struct JsonEventOutput<'a> {
+    #[serde(flatten)]
+    event: EventOutput<'a>,
+    url: Option<String>,
+}
+
+impl<'a> JsonEventOutput<'a> {
+    fn new(event: &'a GitHubEvent, web_base: &str) -> Self {
+        JsonEventOutput {
+            event: EventOutput::from(event),
+            url: event_url(event, web_base),
+        }
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().try_get_matches().unwrap_or_else(|e| usage_error(e));
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| usage_error(e));
+    if let Some(shell) = cli.generate_completions {
+        clap_complete::generate(shell, &mut Cli::command(), "github-activity", &mut io::stdout());
+        return Ok(());
+    }
+    if cli.generate_man {
+        clap_mangen::Man::new(Cli::command()).render(&mut io::stdout())?;
+        return Ok(());
+    }
+    init_logging(cli.log_level);
+
+    if let Err(e) = apply_config(&mut cli, &matches) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    apply_enterprise_token(&mut cli, &matches);
+    // Checked here rather than with clap's `requires`, which doesn't see
+    // tokens from the config file or GITHUB_ENTERPRISE_TOKEN.
+    if cli.include_private && cli.token.is_none() {
+        usage_error(Cli::command().error(
+            ErrorKind::MissingRequiredArgument,
+            "--include-private needs a token: pass --token, set GITHUB_TOKEN or add `token` to the config file",
+        ));
+    }
+
+    let mut failed = match resolve_usernames(&mut cli) {
+        Ok(failed) => failed,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+    };
+    if cli.usernames.is_empty() && !failed {
+        usage_error(Cli::command().error(ErrorKind::MissingRequiredArgument, "no usernames given"));
+    }
+
+    // From here on `--max-age` is just a `--since` relative to now.
+    if let Some(age) = cli.max_age {
+        cli.since = Some(Utc::now().checked_sub_signed(age).unwrap_or(DateTime::<Utc>::MIN_UTC));
+    }
+    if let (Some(since), Some(until)) = (cli.since, cli.until) {
+        if since > until {
+            let flag = if cli.max_age.is_some() { "--max-age" } else { "--since" };
+            usage_error(Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                format!("{} must not be later than --until", flag),
+            ));
+        }
+    }
+
+    if let (Some(start), Some(end)) = (cli.page_start, cli.page_end) {
+        if start > end {
+            usage_error(Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                "--page-start must not be later than --page-end",
+            ));
+        }
+    }
+
+    if cli.token.is_none() && !cli.quiet {
+        eprintln!("Note: running unauthenticated (60 requests/hour). Use --token or set GITHUB_TOKEN to raise the limit to 5000.");
+    }
     
-    match fetch_user_activity(&cli.username).await {
-        Ok(events) => {
-            if events.is_empty() {
-                println!("No recent activity found for user: {}", cli.username);
-            } else {
-                println!("Recent activity for {}:", cli.username);
-                println!();
-                for event in events {
-                    println!("- {}", format_activity(&event));
+    let client = match build_client(&cli) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+    };
+    let mut options = FetchOptions {
+        base_url: cli.base_url.clone(),
+        feed: if cli.org {
+            Feed::Org
+        } else if cli.received && cli.include_private {
+            Feed::Received
+        } else if cli.received {
+            Feed::ReceivedPublic
+        } else {
+            Feed::User
+        },
+        token: cli.token.clone(),
+        user_agent: cli.user_agent.clone(),
+        pages: page_range(&cli).count() as u32,
+        first_page: *page_range(&cli).start(),
+        retries: cli.retries,
+        timeout: Duration::from_secs(cli.timeout),
+        client: Some(client),
+        cache: if cli.no_cache {
+            None
+        } else {
+            // In watch mode every poll revalidates with the ETag instead of
+            // serving a cached copy.
+            let ttl = if cli.watch { 0 } else { cli.cache_ttl };
+            let not_found_ttl = if cli.no_negative_cache { Duration::ZERO } else { NOT_FOUND_TTL };
+            cli.cache_dir
+                .clone()
+                .or_else(Cache::default_dir)
+                .map(|dir| Cache::new(dir, Duration::from_secs(ttl)).with_not_found_ttl(not_found_ttl))
+        },
+        progress: None,
+        timing: None,
+        raw: cli.json_raw,
+    };
+
+    let started = Instant::now();
+    let requests: Arc<Mutex<Vec<(String, Duration)>>> = Arc::default();
+    if cli.timing {
+        let requests = requests.clone();
+        options.timing = Some(Timing::new(move |url, elapsed| {
+            requests.lock().unwrap().push((url.to_string(), elapsed));
+        }));
+    }
+
+    if cli.dry_run {
+        print_plan(&mut io::stdout().lock(), &cli, &options)?;
+        return Ok(());
+    }
+
+    // Everything from here on talks to the API or writes output, so it is
+    // what Ctrl-C cancels. Dropping it flushes the buffered output.
+    let run = async move {
+        if cli.include_private {
+            if let Err(e) = check_token_owner(&cli, &options).await {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+
+        let mut out = match open_output(cli.output.as_deref()) {
+            Ok(out) => out,
+            Err(e) => {
+                eprintln!("Error: {:#}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let mut reports = Vec::new();
+        let mut rate_limited = false;
+        let mut raw = Vec::new();
+        let mut duplicates = 0;
+        let mut seen = Seen::default();
+        let mut poll_interval = None;
+        for (username, result) in fetch_all(&cli, &options).await {
+            match result {
+                Ok(activity) => {
+                    poll_interval = poll_interval.max(activity.poll_interval);
+                    if let Some(new) = &activity.renamed_to {
+                        if !cli.quiet {
+                            eprintln!("Note: '{}' was renamed to '{}'", username, new);
+                        }
+                    }
+                    raw.extend(activity.raw_events(cli.include_private).into_iter().map(ToOwned::to_owned));
+                    duplicates += activity.duplicates;
+                    if cli.watch {
+                        seen.take_new(username, filter_events(&cli, activity.events.clone()));
+                    }
+                    let empty = activity.events.is_empty();
+                    let mut report = Report::new(&cli, username, activity.events);
+                    // An empty feed can also mean a suspended or deleted
+                    // account; only the profile tells them apart. Later
+                    // pages are empty for any quiet account.
+                    if empty && options.first_page == 1 && !cli.org && !cli.received && shows_account_note(&cli) {
+                        report.account = account_status(username, &options).await.ok();
+                    }
+                    reports.push(report);
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    if e.is_rate_limit() {
+                        rate_limited = true;
+                    } else {
+                        failed = true;
+                    }
                 }
             }
         }
-        Err(e) => {
-            eprintln!("Error: {}", e);
+
+        #[cfg(feature = "tui")]
+        if cli.tui {
+            let web_base = web_base_url(&cli.base_url);
+            let entries = reports
+                .iter()
+                .flat_map(|report| &report.events)
+                .map(|event| tui::Entry {
+                    event_type: event.event_type.clone(),
+                    line: format!("[{}] {}", event_time(&cli, event), plain_summary(&cli, event)),
+                    url: event_url(event, &web_base),
+                })
+                .collect();
+            tui::run(entries)?;
+            exit_if_failed(failed, rate_limited);
+            return Ok(());
+        }
+
+        let mut style = Style::new(cli.color.enabled(cli.output.is_some()));
+        if cli.links {
+            let to_terminal = cli.output.is_none() && io::stdout().is_terminal() && !dumb_terminal();
+            let link_style = if to_terminal { LinkStyle::Osc8 } else { LinkStyle::Url };
+            style = style.with_links(link_style, web_base_url(&cli.base_url));
+        }
+        if cli.plain_repo {
+            style = style.with_plain_repos();
+        }
+        match cli.format {
+            _ if cli.json_raw => writeln!(out, "{}", serde_json::to_string(&raw)?)?,
+            _ if cli.count_only => writeln!(out, "{}", reports.iter().map(|report| report.total).sum::<usize>())?,
+            _ if cli.shas => {
+                for event in reports.iter().flat_map(|report| &report.events) {
+                    for sha in commit_shas(event) {
+                        writeln!(out, "{} {}", event.repo.name, sha)?;
+                    }
+                }
+            }
+            _ if cli.count_by_repo => {
+                for (i, report) in reports.iter().enumerate() {
+                    if i > 0 && !cli.quiet {
+                        writeln!(out)?;
+                    }
+                    print_repo_counts(&mut out, &cli, &style, report)?;
+                }
+            }
+            // Also over a `format` from the config file, which is only a default.
+            _ if cli.digest => {
+                for report in &reports {
+                    print_digest(&mut out, &cli, &style, report)?;
+                }
+            }
+            OutputFormat::Text => {
+                for (i, report) in reports.iter().enumerate() {
+                    if i > 0 && !cli.quiet {
+                        writeln!(out)?;
+                    }
+                    print_text(&mut out, &cli, &style, report)?;
+                }
+            }
+            OutputFormat::Json => print_json(&mut out, &cli, reports.iter().flat_map(|report| &report.events))?,
+            OutputFormat::Jsonl => print_jsonl(&mut out, &cli, reports.iter().flat_map(|report| &report.events))?,
+            OutputFormat::Csv => print_csv(&mut out, reports.iter().flat_map(|report| &report.events), true)?,
+            OutputFormat::Table => {
+                for (i, report) in reports.iter().enumerate() {
+                    if i > 0 && !cli.quiet {
+                        writeln!(out)?;
+                    }
+                    print_table(&mut out, &cli, report)?;
+                }
+            }
+            OutputFormat::Markdown => {
+                let style = markdown_style(&cli);
+                for (i, report) in reports.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(out)?;
+                    }
+                    print_markdown(&mut out, &cli, &style, report)?;
+                }
+            }
+        }
+        out.flush()?;
+
+        if cli.timing {
+            print_timing(&mut io::stderr().lock(), &requests.lock().unwrap(), duplicates, started.elapsed())?;
+        }
+
+        if cli.open {
+            for url in open_targets(&cli, &reports) {
+                open_in_browser(&url);
+            }
+        }
+
+        if cli.watch {
+            return watch(&mut out, &cli, &options, &style, seen, poll_interval).await;
+        }
+
+        exit_if_failed(failed, rate_limited);
+        if cli.fail_on_empty && reports.iter().any(|report| report.total == 0) {
+            std::process::exit(2);
+        }
+
+        Ok::<(), anyhow::Error>(())
+    };
+    let result = tokio::select! {
+        result = run => Some(result),
+        _ = tokio::signal::ctrl_c() => None,
+    };
+    match result {
+        Some(result) => result,
+        None => interrupted(),
+    }
+}
+
+/// Prints a command-line error and exits with status 1, keeping 2 for
+/// `--fail-on-empty`. Help and version output still exit with 0.
+fn usage_error(error: clap::Error) -> ! {
+    match error.kind() {
+        ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => error.exit(),
+        _ => {
+            let _ = error.print();
             std::process::exit(1);
         }
     }
+}
+
+/// Exits with status 1 after errors, or 3 when rate limits were the only
+/// failures, so monitoring can tell "try again later" from a real problem.
+fn exit_if_failed(failed: bool, rate_limited: bool) {
+    if failed {
+        std::process::exit(1);
+    }
+    if rate_limited {
+        std::process::exit(3);
+    }
+}
+
+/// Reports a Ctrl-C and exits with the conventional status 130, clearing
+/// a spinner left on the terminal first.
+fn interrupted() -> ! {
+    if io::stderr().is_terminal() && !dumb_terminal() {
+        eprint!("\r\x1b[2K");
+    }
+    eprintln!("Interrupted");
+    std::process::exit(130);
+}
 
+/// Fills in flags left at their defaults from the config file.
+///
+/// Flags given on the command line or through an environment variable win
+/// over the file. Unknown keys are reported but otherwise ignored.
+fn apply_config(cli: &mut Cli, matches: &ArgMatches) -> Result<(), String> {
+    let config = match &cli.config {
+        Some(path) => Config::load(path, false),
+        None => match Config::default_path() {
+            Some(path) => Config::load(&path, true),
+            None => Ok(Config::default()),
+        },
+    }
+    .map_err(|e| e.to_string())?;
+    for key in config.unknown.keys() {
+        eprintln!("Warning: ignoring unknown config key '{}'", key);
+    }
+
+    let unset = |id: &str| matches!(matches.value_source(id), None | Some(ValueSource::DefaultValue));
+    let invalid = |key: &str, e: String| format!("invalid '{}' in config file: {}", key, e);
+    if let Some(token) = config.token.filter(|_| unset("token")) {
+        cli.token = Some(token);
+    }
+    if let Some(base_url) = config.base_url.filter(|_| unset("base_url")) {
+        cli.base_url = parse_base_url(&base_url).map_err(|e| invalid("base-url", e))?;
+    }
+    if let Some(timezone) = config.timezone.filter(|_| unset("timezone")) {
+        cli.timezone = Zone::parse(&timezone).map_err(|e| invalid("timezone", e))?;
+    }
+    if let Some(format) = config.format.filter(|_| unset("format")) {
+        cli.format = OutputFormat::from_str(&format, true).map_err(|e| invalid("format", e))?;
+    }
+    if let Some(color) = config.color.filter(|_| unset("color")) {
+        cli.color = ColorChoice::from_str(&color, true).map_err(|e| invalid("color", e))?;
+    }
+    if let Some(pages) = config.pages.filter(|_| unset("pages")) {
+        if !(1..=MAX_PAGES).contains(&pages) {
+            return Err(invalid("pages", format!("{} is not in 1..={}", pages, MAX_PAGES)));
+        }
+        cli.pages = pages;
+    }
+    if let Some(absolute_time) = config.absolute_time.filter(|_| unset("absolute_time")) {
+        cli.absolute_time = absolute_time;
+    }
     Ok(())
 }
 
-async fn fetch_user_activity(username: &str) -> Result<Vec<GitHubEvent>> {
-    let url = format!("https://api.github.com/users/{}/events", username);
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&url)
-        .header("User-Agent", "github-activity-cli")
-        .send()
-        .await?;
-
-    match response.status() {
-        reqwest::StatusCode::OK => {
-            let events: Vec<GitHubEvent> = response.json().await?;
-            Ok(events)
-        }
-        reqwest::StatusCode::NOT_FOUND => {
-            Err(anyhow!("User '{}' not found", username))
-        }
-        reqwest::StatusCode::FORBIDDEN => {
-            Err(anyhow!("API rate limit exceeded. Please try again later."))
-        }
-        status => {
-            Err(anyhow!("GitHub API request failed with status: {}", status))
-        }
-    }
-}
-
-fn format_activity(event: &GitHubEvent) -> String {
-    match event.event_type.as_str() {
-        "PushEvent" => {
-            let commits = event.payload.get("commits")
-                .and_then(|c| c.as_array())
-                .map(|c| c.len())
-                .unwrap_or(0);
-            format!("Pushed {} commit{} to {}", 
-                   commits, 
-                   if commits == 1 { "" } else { "s" }, 
-                   event.repo.name)
-        }
-        "CreateEvent" => {
-            let ref_type = event.payload.get("ref_type")
-                .and_then(|r| r.as_str())
-                .unwrap_or("repository");
-            match ref_type {
-                "repository" => format!("Created repository {}", event.repo.name),
-                "branch" => {
-                    let branch = event.payload.get("ref")
-                        .and_then(|r| r.as_str())
-                        .unwrap_or("unknown");
-                    format!("Created branch '{}' in {}", branch, event.repo.name)
+/// Uses `GITHUB_ENTERPRISE_TOKEN` when `--base-url` points at a GitHub
+/// Enterprise host and no `--token` was given.
+///
+/// This keeps a github.com token in `GITHUB_TOKEN` from being sent to an
+/// internal host when both are set. Without `GITHUB_ENTERPRISE_TOKEN`,
+/// `GITHUB_TOKEN` (or the config file) is used as before; the Enterprise
+/// token is never sent to github.com.
+fn apply_enterprise_token(cli: &mut Cli, matches: &ArgMatches) {
+    if matches.value_source("token") == Some(ValueSource::CommandLine)
+        || cli.base_url.trim_end_matches('/') == DEFAULT_BASE_URL
+    {
+        return;
+    }
+    if let Some(token) = std::env::var("GITHUB_ENTERPRISE_TOKEN").ok().filter(|t| !t.trim().is_empty()) {
+        cli.token = Some(token);
+    }
+}
+
+/// Replaces `-` with the usernames on stdin and appends those from
+/// `--from-file`.
+///
+/// Invalid lines are reported and skipped, so one typo doesn't abort a
+/// team report; the returned flag tells whether any were found.
+fn resolve_usernames(cli: &mut Cli) -> Result<bool> {
+    let mut lines = Vec::new();
+    let mut usernames = Vec::new();
+    for username in cli.usernames.drain(..) {
+        if username == "-" {
+            let mut stdin = String::new();
+            io::stdin()
+                .read_to_string(&mut stdin)
+                .context("could not read usernames from stdin")?;
+            lines.extend(stdin.lines().map(str::to_string));
+        } else {
+            usernames.push(username);
+        }
+    }
+    if let Some(path) = &cli.from_file {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        lines.extend(contents.lines().map(str::to_string));
+    }
+
+    let mut invalid = false;
+    for line in &lines {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        match normalize_username(line) {
+            Ok(username) => usernames.push(username),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                invalid = true;
+            }
+        }
+    }
+    cli.usernames = usernames;
+    Ok(invalid)
+}
+
+/// The pages to fetch: `--page-start` to `--page-end` when either is given,
+/// otherwise the first `--pages`. GitHub serves at most [`MAX_PAGES`].
+fn page_range(cli: &Cli) -> RangeInclusive<u32> {
+    match (cli.page_start, cli.page_end) {
+        (None, None) => 1..=cli.pages,
+        (start, end) => start.unwrap_or(1)..=end.unwrap_or(MAX_PAGES),
+    }
+}
+
+/// Describes what a run would do for `--dry-run`.
+fn print_plan(out: &mut dyn Write, cli: &Cli, options: &FetchOptions) -> io::Result<()> {
+    writeln!(out, "Dry run: no requests are sent.")?;
+    writeln!(out)?;
+    let pages = page_range(cli);
+    if *pages.start() == 1 {
+        writeln!(out, "Requests (up to {} page{} per user, stopping at the first short page):", 
+                 options.pages, 
+                 if options.pages == 1 { "" } else { "s" })?;
+    } else {
+        writeln!(out, "Requests (pages {} to {} per user, stopping at the first short page):", 
+                 pages.start(), 
+                 pages.end())?;
+    }
+    if cli.include_private {
+        writeln!(out, "  GET {}/user (to check the token's owner)", options.base_url.trim_end_matches('/'))?;
+    }
+    for username in &cli.usernames {
+        for page in pages.clone() {
+            writeln!(out, "  GET {}", page_url(username, options, page))?;
+        }
+    }
+    writeln!(out)?;
+    writeln!(out, "Headers:")?;
+    writeln!(out, "  User-Agent: {}", options.user_agent)?;
+    if options.token.is_some() {
+        writeln!(out, "  Authorization: Bearer <redacted>")?;
+    }
+    writeln!(out)?;
+    writeln!(out, "Settings:")?;
+    writeln!(out, "  timeout: {}s, retries: {}, concurrency: {}", cli.timeout, options.retries, cli.concurrency)?;
+    match &options.cache {
+        Some(cache) => writeln!(
+            out,
+            "  cache: {} (ttl {}s, not found {}s)",
+            cache.dir().display(),
+            if cli.watch { 0 } else { cli.cache_ttl },
+            if cli.no_negative_cache { 0 } else { NOT_FOUND_TTL.as_secs() }
+        )?,
+        None => writeln!(out, "  cache: disabled")?,
+    }
+    if cli.proxy.is_some() {
+        writeln!(out, "  proxy: set with --proxy")?;
+    }
+    writeln!(out)?;
+
+    let mut filters = Vec::new();
+    if !cli.event_types.is_empty() {
+        filters.push(format!("type: {}", cli.event_types.join(", ")));
+    }
+    if !cli.excluded_types.is_empty() {
+        filters.push(format!("exclude type: {}", cli.excluded_types.join(", ")));
+    }
+    if let Some(repo) = &cli.repo {
+        filters.push(format!("repo: {}", repo));
+    }
+    if let Some(prefix) = &cli.repo_prefix {
+        filters.push(format!("repo prefix: {}", prefix));
+    }
+    if let Some(min) = cli.min_commits {
+        filters.push(format!("min commits: {}", min));
+    }
+    if let Some(since) = cli.since {
+        filters.push(format!("since: {}", since.to_rfc3339()));
+    }
+    if let Some(until) = cli.until {
+        filters.push(format!("until: {}", until.to_rfc3339()));
+    }
+    if !cli.actors.is_empty() {
+        filters.push(format!("actor: {}", cli.actors.join(", ")));
+    }
+    if cli.no_bots {
+        filters.push("no bots".to_string());
+    }
+    if cli.include_private {
+        filters.push("including private events".to_string());
+    }
+    if let Some(limit) = cli.limit {
+        filters.push(format!("limit: {}{}", limit, if cli.reverse { " (oldest)" } else { "" }));
+    }
+    writeln!(out, "Filters:")?;
+    if filters.is_empty() {
+        writeln!(out, "  (none)")?;
+    }
+    for filter in filters {
+        writeln!(out, "  {}", filter)?;
+    }
+    Ok(())
+}
+
+/// Makes sure every requested user owns the token, as GitHub only returns
+/// private events to their owner.
+async fn check_token_owner(cli: &Cli, options: &FetchOptions) -> Result<(), String> {
+    let owner = token_owner(options)
+        .await
+        .map_err(|e| format!("could not look up the owner of the token: {}", e))?;
+    if let Some(username) = cli.usernames.iter().find(|username| !username.eq_ignore_ascii_case(&owner.login)) {
+        return Err(format!(
+            "--include-private only works for the token's owner ({}), not '{}'; private events of other users are not accessible",
+            owner.login, username
+        ));
+    }
+    if !owner.can_read_private_repos() {
+        let scopes = owner.scopes.unwrap_or_default();
+        return Err(format!(
+            "--include-private needs a token with the 'repo' scope to see private repositories, but this token has {}",
+            if scopes.is_empty() { "no scopes".to_string() } else { format!("only: {}", scopes.join(", ")) }
+        ));
+    }
+    Ok(())
+}
+
+/// Opens `--output` for writing, creating parent directories, or stdout.
+fn open_output(path: Option<&Path>) -> Result<Box<dyn Write>> {
+    let Some(path) = path else {
+        return Ok(Box::new(io::stdout().lock()));
+    };
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("could not create directory {}", parent.display()))?;
+    }
+    let file = File::create(path)
+        .with_context(|| format!("could not write to {}", path.display()))?;
+    Ok(Box::new(BufWriter::new(file)))
+}
+
+/// Builds the HTTP client shared by all requests, honoring `--proxy`.
+///
+/// Without `--proxy`, reqwest picks up `HTTPS_PROXY`, `HTTP_PROXY`,
+/// `ALL_PROXY` and `NO_PROXY` from the environment.
+fn build_client(cli: &Cli) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy) = &cli.proxy {
+        builder = builder.proxy(proxy.clone());
+    }
+    builder.build().context("could not build HTTP client")
+}
+
+fn init_logging(level: LevelFilter) {
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal() && !no_color() && !dumb_terminal())
+        .without_time()
+        .with_target(false);
+    tracing_subscriber::registry()
+        .with(layer.with_filter(Targets::new().with_target("github_activity", level)))
+        .init();
+}
+
+async fn fetch_all<'a>(
+    cli: &'a Cli,
+    options: &FetchOptions,
+) -> Vec<(&'a str, Result<Activity, ActivityError>)> {
+    let spinner = if cli.quiet { None } else { spinner() };
+    let mut options = options.clone();
+    if let Some(spinner) = &spinner {
+        let spinner = spinner.clone();
+        let users = cli.usernames.len();
+        options.progress = Some(Progress::new(move |username, page, pages| {
+            let mut message = format!("Fetching {}", username);
+            if pages > 1 {
+                message.push_str(&format!(" (page {}/{})", page, pages));
+            }
+            if users > 1 {
+                message.push_str(&format!(" [{} users]", users));
+            }
+            spinner.set_message(message);
+        }));
+    }
+
+    let semaphore = Semaphore::new(cli.concurrency as usize);
+    let results = join_all(cli.usernames.iter().map(|username| async {
+        let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+        fetch_activity(username, &options).await
+    }))
+    .await;
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+    cli.usernames.iter().map(String::as_str).zip(results).collect()
+}
+
+/// A spinner on stderr, or `None` when stderr is not a terminal that can
+/// redraw it.
+fn spinner() -> Option<ProgressBar> {
+    if !io::stderr().is_terminal() || dumb_terminal() {
+        return None;
+    }
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(ProgressStyle::with_template("{spinner} {msg}").unwrap());
+    spinner.set_message("Fetching activity");
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    Some(spinner)
+}
+
+/// Events already fetched, so each poll only shows what is new.
+#[derive(Default)]
+struct Seen {
+    ids: HashSet<String>,
+    newest: HashMap<String, DateTime<Utc>>,
+}
+
+impl Seen {
+    /// Keeps the events of `username` that are newer than anything seen
+    /// before and that have not been printed yet, oldest first.
+    fn take_new(&mut self, username: &str, events: Vec<GitHubEvent>) -> Vec<GitHubEvent> {
+        let newest = self.newest.get(username).copied();
+        let mut fresh: Vec<GitHubEvent> = events
+            .into_iter()
+            .filter(|event| match newest {
+                Some(newest) => event.created_at >= newest,
+                None => true,
+            })
+            .filter(|event| self.ids.insert(event.id.clone()))
+            .collect();
+        fresh.reverse();
+
+        if let Some(latest) = fresh.last() {
+            self.newest.insert(username.to_string(), latest.created_at);
+        }
+        fresh
+    }
+}
+
+/// Polls for new events until the process is interrupted.
+async fn watch(
+    out: &mut dyn Write,
+    cli: &Cli,
+    options: &FetchOptions,
+    style: &Style,
+    mut seen: Seen,
+    mut poll_interval: Option<Duration>,
+) -> Result<()> {
+    let interval = Duration::from_secs(cli.interval);
+    loop {
+        tokio::time::sleep(poll_interval.map_or(interval, |p| p.max(interval))).await;
+
+        poll_interval = None;
+        for (username, result) in fetch_all(cli, options).await {
+            match result {
+                Ok(activity) => {
+                    poll_interval = poll_interval.max(activity.poll_interval);
+                    let events = collapse_and_trim_pushes(cli, seen.take_new(username, filter_events(cli, activity.events)));
+                    if events.is_empty() {
+                        continue;
+                    }
+                    match cli.format {
+                        OutputFormat::Text => {
+                            for event in &events {
+                                print_event(out, cli, style, event, "")?;
+                            }
+                        }
+                        OutputFormat::Json => print_json(out, cli, events.iter())?,
+                        OutputFormat::Jsonl => print_jsonl(out, cli, events.iter())?,
+                        OutputFormat::Csv => print_csv(out, events.iter(), false)?,
+                        OutputFormat::Markdown => {
+                            let style = markdown_style(cli);
+                            for event in &events {
+                                writeln!(out, "- {}", style.event(event, &format_activity(event)))?;
+                            }
+                        }
+                        OutputFormat::Table => writeln!(out, "{}", event_table(cli, &events))?,
+                    }
+                    out.flush()?;
                 }
-                "tag" => {
-                    let tag = event.payload.get("ref")
-                        .and_then(|r| r.as_str())
-                        .unwrap_or("unknown");
-                    format!("Created tag '{}' in {}", tag, event.repo.name)
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+    }
+}
+
+/// The filtered activity of one user, ready to be printed.
+struct Report<'a> {
+    username: &'a str,
+    events: Vec<GitHubEvent>,
+    /// Number of events that matched the filters before `--limit` applied.
+    total: usize,
+    /// Set when the feed was empty and the profile was looked up.
+    account: Option<AccountStatus>,
+}
+
+impl<'a> Report<'a> {
+    fn new(cli: &Cli, username: &'a str, events: Vec<GitHubEvent>) -> Self {
+        let mut events = collapse_and_trim_pushes(cli, filter_events(cli, events));
+        if cli.reverse {
+            events.reverse();
+        }
+        let total = events.len();
+        // With --count-by-repo the limit caps the repositories instead, and
+        // --digest always summarizes every matching event.
+        if let Some(limit) = cli.limit.filter(|_| !cli.count_by_repo && !cli.digest) {
+            events.truncate(limit);
+        }
+
+        Report { username, events, total, account: None }
+    }
+}
+
+/// Applies `--collapse` and then `--min-commits`, so a run of small pushes
+/// can count as one.
+fn collapse_and_trim_pushes(cli: &Cli, mut events: Vec<GitHubEvent>) -> Vec<GitHubEvent> {
+    if cli.collapse {
+        events = collapse_pushes(events);
+    }
+    if let Some(min) = cli.min_commits {
+        events.retain(|event| event.event_type != "PushEvent" || push_commits(event) >= min);
+    }
+    events
+}
+
+/// Bot accounts whose logins lack the `[bot]` suffix of GitHub Apps.
+const KNOWN_BOTS: &[&str] = &[
+    "dependabot-preview",
+    "greenkeeper",
+    "greenkeeperio-bot",
+    "renovate-bot",
+    "snyk-bot",
+    "codecov-io",
+    "allcontributors",
+];
+
+fn is_bot(login: &str) -> bool {
+    login.ends_with("[bot]") || KNOWN_BOTS.iter().any(|bot| bot.eq_ignore_ascii_case(login))
+}
+
+/// Applies `--include-private` and the `--type`, `--exclude-type`, `--repo`,
+/// `--repo-prefix`, `--actor`, `--no-bots` and date filters.
+fn filter_events(cli: &Cli, mut events: Vec<GitHubEvent>) -> Vec<GitHubEvent> {
+    if !cli.include_private {
+        events.retain(|event| event.public);
+    }
+
+    if !cli.event_types.is_empty() {
+        events.retain(|event| {
+            cli.event_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(&event.event_type))
+        });
+    }
+    // Exclusions run last so they win when a type is both included and excluded.
+    if !cli.excluded_types.is_empty() {
+        events.retain(|event| {
+            !cli.excluded_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(&event.event_type))
+        });
+    }
+
+    if let Some(repo) = &cli.repo {
+        events.retain(|event| repo_matches(repo, &event.repo.name));
+    }
+    if let Some(prefix) = &cli.repo_prefix {
+        let prefix = prefix.to_lowercase();
+        events.retain(|event| event.repo.name.to_lowercase().starts_with(&prefix));
+    }
+
+    if !cli.actors.is_empty() {
+        events.retain(|event| cli.actors.iter().any(|actor| actor.eq_ignore_ascii_case(&event.actor.login)));
+    }
+    if cli.no_bots {
+        events.retain(|event| !is_bot(&event.actor.login));
+    }
+
+    if let Some(since) = cli.since {
+        events.retain(|event| event.created_at >= since);
+    }
+    if let Some(until) = cli.until {
+        events.retain(|event| event.created_at <= until);
+    }
+
+    events
+}
+
+fn print_text(out: &mut dyn Write, cli: &Cli, style: &Style, report: &Report) -> io::Result<()> {
+    let events = &report.events;
+    if events.is_empty() {
+        if cli.quiet {
+            return Ok(());
+        }
+        let kind = if cli.event_types.is_empty() {
+            "recent".to_string()
+        } else {
+            cli.event_types.join(" or ")
+        };
+        let mut scope = match (&cli.repo, &cli.repo_prefix) {
+            (Some(repo), _) => format!(" in {}", repo),
+            (None, Some(prefix)) => format!(" in {}*", prefix),
+            (None, None) => String::new(),
+        };
+        if !cli.actors.is_empty() {
+            scope.push_str(&format!(" by {}", cli.actors.join(" or ")));
+        }
+        let subject = if cli.org { "organization" } else { "user" };
+        match account_note(report.account) {
+            Some(note) => writeln!(out, "No {} activity{} found for {}: {} ({})", kind, scope, subject, report.username, note)?,
+            None => writeln!(out, "No {} activity{} found for {}: {}", kind, scope, subject, report.username)?,
+        }
+    } else {
+        if !cli.quiet {
+            if cli.received {
+                writeln!(out, "Activity received by {}:", report.username)?;
+            } else {
+                writeln!(out, "Recent activity for {}:", report.username)?;
+            }
+            writeln!(out)?;
+        }
+        match cli.group_by {
+            Some(GroupBy::Repo) => {
+                for (repo, group) in group_by_repo(events) {
+                    writeln!(out, "{} ({} event{})", style.repo(repo), group.len(), if group.len() == 1 { "" } else { "s" })?;
+                    for event in group {
+                        print_event(out, cli, style, event, "  ")?;
+                    }
+                    if !cli.quiet {
+                        writeln!(out)?;
+                    }
+                }
+            }
+            Some(GroupBy::Week) => {
+                for (week, group) in group_by_week(events, cli.timezone) {
+                    writeln!(out, "Week {} ({} event{})", format_week(week), group.len(), if group.len() == 1 { "" } else { "s" })?;
+                    for event in group {
+                        print_event(out, cli, style, event, "  ")?;
+                    }
+                    if !cli.quiet {
+                        writeln!(out)?;
+                    }
                 }
-                _ => format!("Created {} in {}", ref_type, event.repo.name)
-            }
-        }
-        "DeleteEvent" => {
-            let ref_type = event.payload.get("ref_type")
-                .and_then(|r| r.as_str())
-                .unwrap_or("branch");
-            let ref_name = event.payload.get("ref")
-                .and_then(|r| r.as_str())
-                .unwrap_or("unknown");
-            format!("Deleted {} '{}' in {}", ref_type, ref_name, event.repo.name)
-        }
-        "IssuesEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("updated");
-            let issue_number = event.payload.get("issue")
-                .and_then(|i| i.get("number"))
-                .and_then(|n| n.as_u64())
-                .unwrap_or(0);
-            format!("{} issue #{} in {}", 
-                   capitalize_first_letter(action), 
-                   issue_number, 
-                   event.repo.name)
-        }
-        "PullRequestEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("updated");
-            let pr_number = event.payload.get("number")
-                .and_then(|n| n.as_u64())
-                .unwrap_or(0);
-            format!("{} pull request #{} in {}", 
-                   capitalize_first_letter(action), 
-                   pr_number, 
-                   event.repo.name)
-        }
-        "WatchEvent" => {
-            format!("Starred {}", event.repo.name)
-        }
-        "ForkEvent" => {
-            format!("Forked {}", event.repo.name)
-        }
-        "ReleaseEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("published");
-            let release_name = event.payload.get("release")
-                .and_then(|r| r.get("tag_name"))
-                .and_then(|t| t.as_str())
-                .unwrap_or("unknown");
-            format!("{} release {} in {}", 
-                   capitalize_first_letter(action), 
-                   release_name, 
-                   event.repo.name)
-        }
-        "PublicEvent" => {
-            format!("Made {} public", event.repo.name)
-        }
-        "MemberEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("added");
-            format!("{} as collaborator to {}", 
-                   capitalize_first_letter(action), 
-                   event.repo.name)
-        }
-        "IssueCommentEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("created");
-            let issue_number = event.payload.get("issue")
-                .and_then(|i| i.get("number"))
-                .and_then(|n| n.as_u64())
-                .unwrap_or(0);
-            format!("{} comment on issue #{} in {}", 
-                   capitalize_first_letter(action), 
-                   issue_number, 
-                   event.repo.name)
-        }
-        "PullRequestReviewEvent" => {
-            let action = event.payload.get("action")
-                .and_then(|a| a.as_str())
-                .unwrap_or("submitted");
-            let pr_number = event.payload.get("pull_request")
-                .and_then(|pr| pr.get("number"))
-                .and_then(|n| n.as_u64())
-                .unwrap_or(0);
-            format!("{} review on pull request #{} in {}", 
-                   capitalize_first_letter(action), 
-                   pr_number, 
-                   event.repo.name)
+            }
+            None => {
+                for event in events {
+                    print_event(out, cli, style, event, "")?;
+                }
+            }
         }
-        _ => {
-            format!("Performed {} in {}", event.event_type, event.repo.name)
+
+        let hidden = report.total - events.len();
+        if hidden > 0 && !cli.quiet {
+            writeln!(out, "... and {} more event{}", hidden, if hidden == 1 { "" } else { "s" })?;
+        }
+
+        if cli.summary {
+            let tally: Vec<String> = count_by_type(events)
+                .into_iter()
+                .map(|(event_type, count)| format!("{} {}", count, event_type))
+                .collect();
+            if !cli.quiet {
+                writeln!(out)?;
+            }
+            writeln!(out, "Summary: {}", tally.join(", "))?;
+        }
+
+        if cli.commits {
+            if !cli.quiet {
+                writeln!(out)?;
+            }
+            writeln!(out, "Total commits pushed: {}", total_commits(events))?;
+        }
+
+        if cli.stats {
+            if !cli.quiet {
+                writeln!(out)?;
+            }
+            print_stats(out, cli, events)?;
+        }
+
+        if cli.calendar {
+            if !cli.quiet {
+                writeln!(out)?;
+            }
+            print_calendar(out, cli, events)?;
+        }
+
+        if cli.footer {
+            if !cli.quiet {
+                writeln!(out)?;
+            }
+            print_footer(out, cli, events)?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints one line per event type with its count and when it last
+/// happened, most frequent first.
+fn print_footer(out: &mut dyn Write, cli: &Cli, events: &[GitHubEvent]) -> io::Result<()> {
+    let counts = count_by_type(events);
+    let width = counts.iter().map(|(event_type, _)| event_type.len()).max().unwrap_or(0);
+    for (event_type, count) in counts {
+        let latest = events
+            .iter()
+            .filter(|event| event.event_type == event_type)
+            .max_by_key(|event| event.created_at)
+            .expect("counted types have events");
+        writeln!(
+            out,
+            "{:<width$} {} (last {})",
+            format!("{}:", event_type),
+            count,
+            event_time(cli, latest),
+            width = width + 1
+        )?;
+    }
+    Ok(())
+}
+
+fn print_stats(out: &mut dyn Write, cli: &Cli, events: &[GitHubEvent]) -> io::Result<()> {
+    let stats = activity_stats(events, cli.timezone, cli.timezone.date(Utc::now()));
+    let events_suffix = |count: usize| format!("{} event{}", count, if count == 1 { "" } else { "s" });
+
+    writeln!(out, "Stats (from the {} fetched):", events_suffix(events.len()))?;
+    if let Some((day, count)) = stats.busiest_day {
+        writeln!(out, "  Busiest day:      {} ({})", day.format("%Y-%m-%d"), events_suffix(count))?;
+    }
+    if let Some((repo, count)) = &stats.top_repo {
+        writeln!(out, "  Most active repo: {} ({})", repo, events_suffix(*count))?;
+    }
+    writeln!(out, "  Commits pushed:   {}", stats.commits)?;
+    writeln!(out, "  Current streak:   {} day{}", stats.streak, if stats.streak == 1 { "" } else { "s" })?;
+    Ok(())
+}
+
+/// Number of days shown by `--calendar`.
+const CALENDAR_DAYS: u64 = 14;
+
+/// Prints one column per day, labelled with the weekday, shaded by how
+/// many events happened that day relative to the busiest day.
+fn print_calendar(out: &mut dyn Write, cli: &Cli, events: &[GitHubEvent]) -> io::Result<()> {
+    const SHADES: [&str; 5] = ["·", "░", "▒", "▓", "█"];
+
+    let today = cli.timezone.date(Utc::now());
+    let days = count_by_day(events, cli.timezone, today, CALENDAR_DAYS);
+    let busiest = days.iter().map(|(_, count)| *count).max().unwrap_or(0);
+
+    let labels: Vec<String> = days
+        .iter()
+        .map(|(day, _)| day.format("%a").to_string()[..2].to_string())
+        .collect();
+    let cells: Vec<String> = days
+        .iter()
+        .map(|(_, count)| {
+            let shade = if *count == 0 {
+                0
+            } else {
+                (count * (SHADES.len() - 1)).div_ceil(busiest)
+            };
+            format!("{:<2}", SHADES[shade])
+        })
+        .collect();
+
+    writeln!(out, "Last {} days:", CALENDAR_DAYS)?;
+    writeln!(out, "{}", labels.join(" "))?;
+    writeln!(out, "{}", cells.join(" ").trim_end())?;
+    writeln!(out, "{} none, {} busiest day ({} event{})", 
+             SHADES[0], 
+             SHADES[SHADES.len() - 1], 
+             busiest, 
+             if busiest == 1 { "" } else { "s" })?;
+    Ok(())
+}
+
+/// Prints the number of events per repository for `--count-by-repo`,
+/// busiest first and cut to `--limit` repositories.
+fn print_repo_counts(out: &mut dyn Write, cli: &Cli, style: &Style, report: &Report) -> io::Result<()> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for event in &report.events {
+        *counts.entry(event.repo.name.as_str()).or_default() += 1;
+    }
+    let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let hidden = match cli.limit {
+        Some(limit) if counts.len() > limit => counts.split_off(limit).len(),
+        _ => 0,
+    };
+
+    if !cli.quiet {
+        writeln!(out, "Events by repository for {}:", report.username)?;
+        writeln!(out)?;
+    }
+    if counts.is_empty() && !cli.quiet {
+        writeln!(out, "No activity found.")?;
+    }
+    let width = counts.first().map_or(0, |(_, count)| count.to_string().len());
+    for (repo, count) in &counts {
+        writeln!(out, "{:>width$} {}", count, style.repo(repo), width = width)?;
+    }
+    if hidden > 0 && !cli.quiet {
+        writeln!(out, "... and {} more repositor{}", hidden, if hidden == 1 { "y" } else { "ies" })?;
+    }
+    Ok(())
+}
+
+/// Explains an empty feed when the profile says more than "no activity".
+fn account_note(status: Option<AccountStatus>) -> Option<&'static str> {
+    match status? {
+        AccountStatus::Active => None,
+        AccountStatus::Suspended => Some("the account is suspended"),
+        AccountStatus::Ghost => Some("this is the placeholder for deleted accounts"),
+        AccountStatus::Unavailable => Some("the account appears to be suspended or deleted"),
+    }
+}
+
+/// Whether the output names the account status of an empty feed, which
+/// only the text output and `--digest` do and which costs a request.
+fn shows_account_note(cli: &Cli) -> bool {
+    #[cfg(feature = "tui")]
+    if cli.tui {
+        return false;
+    }
+    if cli.watch || cli.json_raw || cli.count_only || cli.shas || cli.count_by_repo {
+        return false;
+    }
+    cli.digest || (matches!(cli.format, OutputFormat::Text) && !cli.quiet)
+}
+
+/// Prints "name: N repos, M events (last: ...)" for `--digest`.
+fn print_digest(out: &mut dyn Write, cli: &Cli, style: &Style, report: &Report) -> io::Result<()> {
+    let events = &report.events;
+    let Some(latest) = events.iter().map(|event| event.created_at).max() else {
+        return match account_note(report.account) {
+            Some(note) => writeln!(out, "{}: no activity ({})", style.bold(report.username), note),
+            None => writeln!(out, "{}: no activity", style.bold(report.username)),
+        };
+    };
+    let repos: HashSet<&str> = events.iter().map(|event| event.repo.name.as_str()).collect();
+    let last = if cli.absolute_time {
+        absolute_time(latest, cli.timezone)
+    } else {
+        humanize_time(latest, cli.timezone)
+    };
+    writeln!(out, "{}: {} repo{}, {} event{} (last: {})", 
+             style.bold(report.username), 
+             repos.len(), 
+             if repos.len() == 1 { "" } else { "s" }, 
+             report.total, 
+             if report.total == 1 { "" } else { "s" }, 
+             last)
+}
+
+fn print_event(
+    out: &mut dyn Write,
+    cli: &Cli,
+    style: &Style,
+    event: &GitHubEvent,
+    indent: &str,
+) -> io::Result<()> {
+    match &cli.template {
+        Some(template) => writeln!(out, "{}{}", indent, render_template(cli, template, event))?,
+        None => writeln!(out, "{}- {}", indent, event_line(cli, style, event))?,
+    }
+    if cli.verbose {
+        for commit in commit_summaries(event) {
+            writeln!(out, "{}    {}", indent, style.dim(&commit))?;
+        }
+    }
+    Ok(())
+}
+
+fn event_line(cli: &Cli, style: &Style, event: &GitHubEvent) -> String {
+    format!(
+        "{} {}",
+        style.dim(&format!("[{}]", event_time(cli, event))),
+        style.event(event, &format_activity(event))
+    )
+}
+
+fn event_time(cli: &Cli, event: &GitHubEvent) -> String {
+    if cli.absolute_time {
+        absolute_time(event.created_at, cli.timezone)
+    } else {
+        humanize_time(event.created_at, cli.timezone)
+    }
+}
+
+fn render_template(cli: &Cli, template: &Template, event: &GitHubEvent) -> String {
+    template.render(|field| match field {
+        Field::Type => event.event_type.clone(),
+        Field::Repo => repo_name(cli, event).to_string(),
+        Field::Actor => event.actor.login.clone(),
+        Field::Time => event_time(cli, event),
+        Field::Summary => plain_summary(cli, event),
+        Field::Url => event_url(event, &web_base_url(&cli.base_url)).unwrap_or_default(),
+        Field::Id => event.id.clone(),
+    })
+}
+
+/// The repository name as shown in text output, see `--plain-repo`.
+fn repo_name<'a>(cli: &Cli, event: &'a GitHubEvent) -> &'a str {
+    if cli.plain_repo {
+        plain_repo(&event.repo.name)
+    } else {
+        &event.repo.name
+    }
+}
+
+/// The event summary without styling, honouring `--plain-repo`.
+fn plain_summary(cli: &Cli, event: &GitHubEvent) -> String {
+    let summary = format_activity(event);
+    if cli.plain_repo {
+        Style::new(false).with_plain_repos().event(event, &summary)
+    } else {
+        summary
+    }
+}
+
+fn markdown_style(cli: &Cli) -> Style {
+    let style = Style::new(false).with_links(LinkStyle::Markdown, web_base_url(&cli.base_url));
+    if cli.plain_repo {
+        style.with_plain_repos()
+    } else {
+        style
+    }
+}
+
+/// Renders a report as a Markdown section with one subheading per day.
+fn print_markdown(out: &mut dyn Write, cli: &Cli, style: &Style, report: &Report) -> io::Result<()> {
+    if cli.received {
+        writeln!(out, "## Activity received by {}", report.username)?;
+    } else {
+        writeln!(out, "## Recent activity for {}", report.username)?;
+    }
+    writeln!(out)?;
+    if report.events.is_empty() {
+        writeln!(out, "No activity found.")?;
+        return Ok(());
+    }
+
+    let mut day = None;
+    for event in &report.events {
+        let date = cli.timezone.date(event.created_at);
+        if day != Some(date) {
+            if day.is_some() {
+                writeln!(out)?;
+            }
+            writeln!(out, "### {}", date.format("%Y-%m-%d"))?;
+            writeln!(out)?;
+            day = Some(date);
         }
+        writeln!(out, "- {}", style.event(event, &format_activity(event)))?;
+    }
+
+    let hidden = report.total - report.events.len();
+    if hidden > 0 {
+        writeln!(out)?;
+        writeln!(out, "_... and {} more event{}_", hidden, if hidden == 1 { "" } else { "s" })?;
     }
+    Ok(())
 }
 
-fn capitalize_first_letter(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+/// Width of `--format table` output when it doesn't go to a terminal.
+const TABLE_WIDTH: u16 = 120;
+
+/// Renders a report as a table, see [`event_table`].
+fn print_table(out: &mut dyn Write, cli: &Cli, report: &Report) -> io::Result<()> {
+    if !cli.quiet {
+        if cli.received {
+            writeln!(out, "Activity received by {}:", report.username)?;
+        } else {
+            writeln!(out, "Recent activity for {}:", report.username)?;
+        }
+    }
+    if report.events.is_empty() {
+        if !cli.quiet {
+            writeln!(out, "No activity found.")?;
+        }
+        return Ok(());
+    }
+    writeln!(out, "{}", event_table(cli, &report.events))?;
+
+    let hidden = report.total - report.events.len();
+    if hidden > 0 && !cli.quiet {
+        writeln!(out, "... and {} more event{}", hidden, if hidden == 1 { "" } else { "s" })?;
     }
+    Ok(())
+}
+
+/// A table of time, type, repository and summary as wide as the terminal,
+/// or [`TABLE_WIDTH`] when the output goes elsewhere. Summaries that don't
+/// fit are cut with "...".
+fn event_table(cli: &Cli, events: &[GitHubEvent]) -> comfy_table::Table {
+    let to_terminal = cli.output.is_none() && io::stdout().is_terminal();
+    let width = terminal_size::terminal_size()
+        .filter(|_| to_terminal)
+        .map_or(TABLE_WIDTH, |(width, _)| width.0);
+
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY)
+        .remove_style(comfy_table::TableComponent::HorizontalLines)
+        .remove_style(comfy_table::TableComponent::MiddleIntersections)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_width(width)
+        .set_header(["Time", "Type", "Repository", "Summary"]);
+    for event in events {
+        let mut row = comfy_table::Row::from([
+            event_time(cli, event),
+            event.event_type.clone(),
+            repo_name(cli, event).to_string(),
+            plain_summary(cli, event),
+        ]);
+        row.max_height(1);
+        table.add_row(row);
+    }
+    table
+}
+
+fn print_json<'a>(
+    out: &mut dyn Write,
+    cli: &Cli,
+    events: impl Iterator<Item = &'a GitHubEvent>,
+) -> Result<()> {
+    let web_base = web_base_url(&cli.base_url);
+    let output: Vec<JsonEventOutput> = events
+        .map(|event| JsonEventOutput::new(event, &web_base))
+        .collect();
+    let json = if cli.prettify_json {
+        serde_json::to_string_pretty(&output)?
+    } else {
+        serde_json::to_string(&output)?
+    };
+    writeln!(out, "{}", json)?;
+    Ok(())
+}
+
+fn print_jsonl<'a>(
+    out: &mut dyn Write,
+    cli: &Cli,
+    events: impl Iterator<Item = &'a GitHubEvent>,
+) -> Result<()> {
+    let web_base = web_base_url(&cli.base_url);
+    for event in events {
+        writeln!(out, "{}", serde_json::to_string(&JsonEventOutput::new(event, &web_base))?)?;
+    }
+    Ok(())
+}
+
+fn print_csv<'a>(
+    out: &mut dyn Write,
+    events: impl Iterator<Item = &'a GitHubEvent>,
+    header: bool,
+) -> Result<()> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(out);
+    if header {
+        writer.write_record(["type", "repo", "actor", "created_at", "summary"])?;
+    }
+    for event in events {
+        writer.serialize(EventOutput::from(event))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints the `--timing` report: one line per request, then the totals and
+/// how many events repeated across pages were dropped.
+fn print_timing(
+    out: &mut dyn Write,
+    requests: &[(String, Duration)],
+    duplicates: usize,
+    elapsed: Duration,
+) -> io::Result<()> {
+    writeln!(out, "Timing:")?;
+    for (url, duration) in requests {
+        writeln!(out, "  {:>6}ms  GET {}", duration.as_millis(), url)?;
+    }
+    let total: Duration = requests.iter().map(|(_, duration)| *duration).sum();
+    writeln!(
+        out,
+        "  {} {} in {:.2}s, {:.2}s wall clock",
+        requests.len(),
+        if requests.len() == 1 { "request" } else { "requests" },
+        total.as_secs_f64(),
+        elapsed.as_secs_f64()
+    )?;
+    if duplicates > 0 {
+        writeln!(out, "  {} duplicate event{} dropped between pages", duplicates, if duplicates == 1 { "" } else { "s" })?;
+    }
+    Ok(())
+}
+
+/// The pages `--open` shows: each user's profile, or with `--repo` the
+/// repository it selects.
+fn open_targets(cli: &Cli, reports: &[Report]) -> Vec<String> {
+    let web_base = web_base_url(&cli.base_url);
+    let mut urls: Vec<String> = reports
+        .iter()
+        .map(|report| match &cli.repo {
+            Some(repo) if repo.contains('/') => format!("{}/{}", web_base, repo),
+            // A bare name is resolved through the events it matched, falling
+            // back to a repository owned by the user.
+            Some(repo) => match report.events.first() {
+                Some(event) => format!("{}/{}", web_base, event.repo.name),
+                None => format!("{}/{}/{}", web_base, report.username, repo),
+            },
+            None => format!("{}/{}", web_base, report.username),
+        })
+        .collect();
+    urls.dedup();
+    urls
+}
+
+/// Opens `url` in the default browser, or prints it when there is none, as
+/// on headless machines.
+fn open_in_browser(url: &str) {
+    if let Err(e) = open::that(url) {
+        tracing::debug!("could not open {}: {}", url, e);
+        eprintln!("Note: no browser could be opened; visit {}", url);
+    }
+}
+
+/// Matches `--repo` against a full `owner/name`; a bare name matches any owner.
+fn repo_matches(filter: &str, repo: &str) -> bool {
+    if filter.contains('/') {
+        filter.eq_ignore_ascii_case(repo)
+    } else {
+        repo.rsplit('/')
+            .next()
+            .is_some_and(|name| name.eq_ignore_ascii_case(filter))
+    }
+}
+
+fn parse_base_url(s: &str) -> Result<String, String> {
+    let url = reqwest::Url::parse(s).map_err(|e| format!("invalid URL '{}': {}", s, e))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(format!("invalid URL '{}': expected an http(s) URL with a host", s));
+    }
+    Ok(s.trim_end_matches('/').to_string())
+}
+
+fn parse_username(s: &str) -> Result<String, String> {
+    if s == "-" {
+        return Ok(s.to_string());
+    }
+    normalize_username(s)
+}
+
+fn parse_user_agent(s: &str) -> Result<String, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("the User-Agent must not be empty; GitHub rejects requests without one".to_string());
+    }
+    reqwest::header::HeaderValue::from_str(s)
+        .map_err(|_| format!("invalid User-Agent '{}': it contains characters not allowed in a header", s))?;
+    Ok(s.to_string())
+}
+
+fn parse_proxy(s: &str) -> Result<reqwest::Proxy, String> {
+    let url = reqwest::Url::parse(s).map_err(|e| format!("invalid proxy URL '{}': {}", s, e))?;
+    if url.host_str().is_none() {
+        return Err(format!("invalid proxy URL '{}': expected a URL with a host", s));
+    }
+    reqwest::Proxy::all(url).map_err(|e| format!("invalid proxy URL '{}': {}", s, e))
+}
+
+/// Accepts a login as written, `@` prefix included, without the stricter
+/// username rules so bots like `dependabot[bot]` can be named.
+fn parse_actor(s: &str) -> Result<String, String> {
+    let login = s.trim();
+    let login = login.strip_prefix('@').unwrap_or(login);
+    if login.is_empty() {
+        return Err("actor cannot be empty".to_string());
+    }
+    Ok(login.to_string())
+}
+
+fn parse_since(s: &str) -> Result<DateTime<Utc>, String> {
+    parse_date_bound(s, false)
+}
+
+fn parse_until(s: &str) -> Result<DateTime<Utc>, String> {
+    parse_date_bound(s, true)
+}
+
+/// Resolves a `--type` argument to its canonical event type name.
+///
+/// Matching is case-insensitive, the `Event` suffix is optional and a few
+/// short aliases (`pr`, `star`, `wiki`, ...) are accepted. Unknown types are
+/// passed through unchanged so new GitHub event types can still be filtered
+/// on.
+fn parse_event_type(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("event type cannot be empty".to_string());
+    }
+
+    let lower = name.to_lowercase();
+    if let Some((_, event_type)) = EVENT_TYPE_ALIASES.iter().find(|(alias, _)| *alias == lower) {
+        return Ok(event_type.to_string());
+    }
+
+    let lower = if lower.ends_with("event") { lower } else { format!("{}event", lower) };
+    let canonical = EVENT_TYPES
+        .iter()
+        .find(|t| t.to_lowercase() == lower)
+        .map(|t| t.to_string())
+        .unwrap_or_else(|| name.to_string());
+    Ok(canonical)
 }
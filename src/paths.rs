@@ -0,0 +1,50 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the CLI keeps its files on this platform.
+///
+/// On Linux this follows the XDG base directories, so `XDG_CONFIG_HOME` and
+/// `XDG_CACHE_HOME` are honored and `~/.config` and `~/.cache` are only the
+/// fallbacks. macOS uses `~/Library/Application Support` and
+/// `~/Library/Caches`, Windows `%APPDATA%` and `%LOCALAPPDATA%`. Nothing is
+/// created here, see [`create_private_dir`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppPaths {
+    /// Directory holding `config.toml`, or `None` without a home directory.
+    pub config_dir: Option<PathBuf>,
+    /// Directory for cached API responses, or `None` without a home directory.
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl AppPaths {
+    /// The default config file, `config.toml` in [`AppPaths::config_dir`].
+    pub fn config_file(&self) -> Option<PathBuf> {
+        self.config_dir.as_ref().map(|dir| dir.join("config.toml"))
+    }
+}
+
+/// Resolves the platform directories of `github-activity`.
+pub fn paths() -> AppPaths {
+    match directories::ProjectDirs::from("", "", "github-activity") {
+        Some(dirs) => AppPaths {
+            config_dir: Some(dirs.config_dir().to_path_buf()),
+            cache_dir: Some(dirs.cache_dir().to_path_buf()),
+        },
+        None => AppPaths::default(),
+    }
+}
+
+/// Creates `dir` and its missing parents. On Unix the directories created
+/// are only accessible to the current user, since cached responses can hold
+/// private events; existing directories are left as they are.
+pub fn create_private_dir(dir: &Path) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(dir)
+}
@@ -0,0 +1,116 @@
+use crate::time::Zone;
+use crate::GitHubEvent;
+use chrono::{Days, NaiveDate};
+use std::collections::{HashMap, HashSet};
+
+/// Counts `events` per event type, most frequent first and alphabetically
+/// for ties.
+pub fn count_by_type(events: &[GitHubEvent]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for event in events {
+        *counts.entry(event.event_type.clone()).or_default() += 1;
+    }
+
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Counts `events` per calendar day in `zone` for the `days` days ending on
+/// `last`, oldest first. Days without events are included with a count of 0
+/// and events outside the range are ignored.
+pub fn count_by_day(
+    events: &[GitHubEvent],
+    zone: Zone,
+    last: NaiveDate,
+    days: u64,
+) -> Vec<(NaiveDate, usize)> {
+    let first = last - Days::new(days.saturating_sub(1));
+    let mut counts: Vec<(NaiveDate, usize)> = first
+        .iter_days()
+        .take_while(|day| *day <= last)
+        .map(|day| (day, 0))
+        .collect();
+    for event in events {
+        let day = zone.date(event.created_at);
+        let Ok(index) = usize::try_from((day - first).num_days()) else {
+            continue;
+        };
+        if let Some((_, count)) = counts.get_mut(index) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// Counts the commits pushed across all `PushEvent`s in `events`, see
+/// [`push_commits`].
+pub fn total_commits(events: &[GitHubEvent]) -> u64 {
+    events
+        .iter()
+        .filter(|event| event.event_type == "PushEvent")
+        .map(push_commits)
+        .sum()
+}
+
+/// Number of commits in a push.
+///
+/// This is the push's `size`, the true number of commits, since the
+/// `commits` array in the payload is capped at 20 entries. Pushes without a
+/// `size` fall back to the length of that array.
+pub fn push_commits(event: &GitHubEvent) -> u64 {
+    event.payload.get("size")
+        .and_then(|s| s.as_u64())
+        .or_else(|| {
+            event.payload.get("commits")
+                .and_then(|c| c.as_array())
+                .map(|c| c.len() as u64)
+        })
+        .unwrap_or(0)
+}
+
+/// Derived figures about a window of events, see [`activity_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityStats {
+    /// The day with the most events and its count; the latest such day on
+    /// ties.
+    pub busiest_day: Option<(NaiveDate, usize)>,
+    /// The repository with the most events and its count; alphabetically
+    /// first on ties.
+    pub top_repo: Option<(String, usize)>,
+    /// Commits pushed, summing the `size` of each `PushEvent`.
+    pub commits: u64,
+    /// Consecutive days with events up to `today`, or up to yesterday when
+    /// nothing happened yet today.
+    pub streak: u32,
+}
+
+/// Computes [`ActivityStats`] for `events`, with days taken in `zone`.
+pub fn activity_stats(events: &[GitHubEvent], zone: Zone, today: NaiveDate) -> ActivityStats {
+    let mut per_day: HashMap<NaiveDate, usize> = HashMap::new();
+    let mut per_repo: HashMap<&str, usize> = HashMap::new();
+    for event in events {
+        *per_day.entry(zone.date(event.created_at)).or_default() += 1;
+        *per_repo.entry(&event.repo.name).or_default() += 1;
+    }
+
+    let busiest_day = per_day.iter()
+        .map(|(day, count)| (*day, *count))
+        .max_by_key(|(day, count)| (*count, *day));
+    let top_repo = per_repo.into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(repo, count)| (repo.to_string(), count));
+
+    let active: HashSet<NaiveDate> = per_day.into_keys().collect();
+    let mut day = today;
+    if !active.contains(&day) {
+        day = day - Days::new(1);
+    }
+    let mut streak = 0;
+    while active.contains(&day) {
+        streak += 1;
+        day = day - Days::new(1);
+    }
+
+    ActivityStats { busiest_day, top_repo, commits: total_commits(events), streak }
+}
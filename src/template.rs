@@ -0,0 +1,104 @@
+/// A value that can be substituted into a [`Template`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// `{type}`: the event type, e.g. `PushEvent`.
+    Type,
+    /// `{repo}`: the repository name.
+    Repo,
+    /// `{actor}`: the login of the user that triggered the event.
+    Actor,
+    /// `{time}`: when the event happened, as displayed elsewhere.
+    Time,
+    /// `{summary}`: the one-line summary from [`format_activity`](crate::format_activity).
+    Summary,
+    /// `{url}`: a link to what the event is about, see [`event_url`](crate::event_url).
+    Url,
+    /// `{id}`: the event id.
+    Id,
+}
+
+const FIELDS: &[(&str, Field)] = &[
+    ("type", Field::Type),
+    ("repo", Field::Repo),
+    ("actor", Field::Actor),
+    ("time", Field::Time),
+    ("summary", Field::Summary),
+    ("url", Field::Url),
+    ("id", Field::Id),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field(Field),
+}
+
+/// A line format with `{field}` placeholders, e.g. `{time} {repo}: {summary}`.
+///
+/// `{{` and `}}` stand for literal braces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+impl Template {
+    /// Parses `s`, rejecting unknown placeholders and unbalanced braces so
+    /// mistakes surface before anything is fetched.
+    pub fn parse(s: &str) -> Result<Template, String> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err("unclosed '{': write '{{' for a literal brace".to_string()),
+                        }
+                    }
+                    let field = FIELDS
+                        .iter()
+                        .find(|(field, _)| *field == name)
+                        .map(|(_, field)| *field)
+                        .ok_or_else(|| {
+                            let known: Vec<String> = FIELDS.iter().map(|(name, _)| format!("{{{}}}", name)).collect();
+                            format!("unknown placeholder '{{{}}}': expected one of {}", name, known.join(", "))
+                        })?;
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Part::Field(field));
+                }
+                '}' => return Err("unmatched '}': write '}}' for a literal brace".to_string()),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Ok(Template { parts })
+    }
+
+    /// Renders the template, looking up each placeholder with `value`.
+    pub fn render(&self, value: impl Fn(Field) -> String) -> String {
+        let mut line = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => line.push_str(text),
+                Part::Field(field) => line.push_str(&value(*field)),
+            }
+        }
+        line
+    }
+}
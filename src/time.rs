@@ -0,0 +1,121 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeDelta, Utc};
+use chrono_tz::Tz;
+
+/// Time zone timestamps are displayed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Zone {
+    /// Coordinated Universal Time.
+    #[default]
+    Utc,
+    /// The system's local time zone.
+    Local,
+    /// An IANA time zone such as `America/New_York`.
+    Named(Tz),
+}
+
+impl Zone {
+    /// Parses `UTC`, `local` or an IANA time zone name.
+    pub fn parse(s: &str) -> Result<Zone, String> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("utc") {
+            Ok(Zone::Utc)
+        } else if s.eq_ignore_ascii_case("local") {
+            Ok(Zone::Local)
+        } else {
+            s.parse::<Tz>().map(Zone::Named).map_err(|_| {
+                format!("unknown time zone '{}': expected an IANA name like America/New_York, UTC or local", s)
+            })
+        }
+    }
+
+    /// Renders `ts` in this zone with a `strftime`-style format.
+    pub fn format(self, ts: DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            Zone::Utc => ts.format(fmt).to_string(),
+            Zone::Local => ts.with_timezone(&Local).format(fmt).to_string(),
+            Zone::Named(tz) => ts.with_timezone(&tz).format(fmt).to_string(),
+        }
+    }
+
+    /// The calendar day `ts` falls on in this zone.
+    pub fn date(self, ts: DateTime<Utc>) -> NaiveDate {
+        match self {
+            Zone::Utc => ts.date_naive(),
+            Zone::Local => ts.with_timezone(&Local).date_naive(),
+            Zone::Named(tz) => ts.with_timezone(&tz).date_naive(),
+        }
+    }
+}
+
+/// Renders `ts` relative to now, e.g. "just now", "5 minutes ago" or
+/// "2 days ago". Timestamps older than 30 days fall back to a plain date
+/// in `zone`.
+pub fn humanize_time(ts: DateTime<Utc>, zone: Zone) -> String {
+    let seconds = (Utc::now() - ts).num_seconds();
+
+    if seconds < 60 {
+        "just now".to_string()
+    } else if seconds < 60 * 60 {
+        plural(seconds / 60, "minute")
+    } else if seconds < 24 * 60 * 60 {
+        plural(seconds / (60 * 60), "hour")
+    } else if seconds < 30 * 24 * 60 * 60 {
+        plural(seconds / (24 * 60 * 60), "day")
+    } else {
+        zone.format(ts, "%Y-%m-%d")
+    }
+}
+
+/// Renders `ts` as a full timestamp in `zone`, e.g.
+/// "2024-01-15 09:30:00 UTC" or "2024-01-15 04:30:00 EST".
+pub fn absolute_time(ts: DateTime<Utc>, zone: Zone) -> String {
+    zone.format(ts, "%Y-%m-%d %H:%M:%S %Z")
+}
+
+fn plural(count: i64, unit: &str) -> String {
+    format!("{} {}{} ago", count, unit, if count == 1 { "" } else { "s" })
+}
+
+/// Parses a date filter bound given as RFC 3339 or `YYYY-MM-DD`.
+///
+/// A bare date is taken as a UTC day boundary: the start of the day, or its
+/// last instant when `end_of_day` is set, so `--until 2024-05-01` still
+/// includes events from that day.
+pub fn parse_date_bound(s: &str, end_of_day: bool) -> Result<DateTime<Utc>, String> {
+    let s = s.trim();
+    if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| format!("invalid date '{}': expected YYYY-MM-DD or RFC 3339", s))?;
+    let time = if end_of_day {
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap()
+    } else {
+        NaiveTime::MIN
+    };
+    Ok(date.and_time(time).and_utc())
+}
+
+/// Parses an age such as `30m`, `6h`, `7d` or `2w`.
+///
+/// The number must be a positive whole count of minutes, hours, days or
+/// weeks; the unit is required so `7` is not silently read as seconds.
+pub fn parse_age(s: &str) -> Result<TimeDelta, String> {
+    let s = s.trim();
+    let invalid = || format!("invalid age '{}': expected a number and a unit, e.g. 30m, 6h, 7d or 2w", s);
+    let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (count, unit) = s.split_at(split);
+    let count: i64 = count.parse().map_err(|_| invalid())?;
+    if count == 0 {
+        return Err(format!("invalid age '{}': it must be greater than zero", s));
+    }
+    let age = match unit {
+        "m" => TimeDelta::try_minutes(count),
+        "h" => TimeDelta::try_hours(count),
+        "d" => TimeDelta::try_days(count),
+        "w" => TimeDelta::try_weeks(count),
+        _ => return Err(invalid()),
+    };
+    age.ok_or_else(|| format!("invalid age '{}': it is too large", s))
+}
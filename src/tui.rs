@@ -0,0 +1,132 @@
+//! Interactive event browser behind `--tui`.
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+
+/// One line of the list, rendered ahead of time from a fetched event.
+pub struct Entry {
+    pub event_type: String,
+    pub line: String,
+    pub url: Option<String>,
+}
+
+struct App {
+    entries: Vec<Entry>,
+    /// Event types present in `entries`, in the order `t` cycles through them.
+    types: Vec<String>,
+    /// Index into `types` of the type being shown, or `None` for all of them.
+    filter: Option<usize>,
+    list: ListState,
+    /// Shown in the status bar until the next key press.
+    message: Option<String>,
+}
+
+impl App {
+    fn new(entries: Vec<Entry>) -> Self {
+        let mut types: Vec<String> = entries.iter().map(|entry| entry.event_type.clone()).collect();
+        types.sort();
+        types.dedup();
+        let mut list = ListState::default();
+        if !entries.is_empty() {
+            list.select(Some(0));
+        }
+        App { entries, types, filter: None, list, message: None }
+    }
+
+    fn visible(&self) -> Vec<&Entry> {
+        let filter = self.filter.map(|i| self.types[i].as_str());
+        self.entries
+            .iter()
+            .filter(|entry| filter.is_none() || filter == Some(entry.event_type.as_str()))
+            .collect()
+    }
+
+    fn cycle_filter(&mut self) {
+        self.filter = match self.filter {
+            None if !self.types.is_empty() => Some(0),
+            Some(i) if i + 1 < self.types.len() => Some(i + 1),
+            _ => None,
+        };
+        self.list.select(if self.visible().is_empty() { None } else { Some(0) });
+    }
+
+    fn open_selected(&mut self) {
+        let url = self
+            .list
+            .selected()
+            .and_then(|i| self.visible().get(i).and_then(|entry| entry.url.clone()));
+        self.message = Some(match url {
+            Some(url) => match open::that_detached(&url) {
+                Ok(()) => format!("Opened {}", url),
+                Err(e) => format!("Could not open {}: {}", url, e),
+            },
+            None => "This event has no link".to_string(),
+        });
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+
+        let title = match self.filter {
+            Some(i) => format!(" {} ", self.types[i]),
+            None => " All events ".to_string(),
+        };
+        let items: Vec<Line> = self.visible().iter().map(|entry| Line::from(entry.line.clone())).collect();
+        let count = items.len();
+        let list = List::new(items)
+            .block(Block::bordered().title(title).title_bottom(format!(" {} events ", count)))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, main, &mut self.list);
+
+        let help = "↑/↓ scroll  t filter by type  a show all  enter/o open  q quit";
+        let text = self.message.as_deref().unwrap_or(help);
+        frame.render_widget(Paragraph::new(text).style(Style::new().add_modifier(Modifier::DIM)), status);
+    }
+
+    /// Handles a key press, returning `false` once the user asked to quit.
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        self.message = None;
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::PageDown => self.list.scroll_down_by(10),
+            KeyCode::PageUp => self.list.scroll_up_by(10),
+            KeyCode::Home | KeyCode::Char('g') => self.list.select_first(),
+            KeyCode::End | KeyCode::Char('G') => self.list.select_last(),
+            KeyCode::Char('t') => self.cycle_filter(),
+            KeyCode::Char('a') => {
+                self.filter = None;
+                self.list.select(if self.entries.is_empty() { None } else { Some(0) });
+            }
+            KeyCode::Enter | KeyCode::Char('o') => self.open_selected(),
+            _ => {}
+        }
+        true
+    }
+}
+
+/// Shows `entries` until the user quits, restoring the terminal afterwards.
+pub fn run(entries: Vec<Entry>) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, App::new(entries));
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, mut app: App) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/// Longest login GitHub allows.
+const MAX_LOGIN_LEN: usize = 39;
+
+/// Cleans up a username as typed or pasted on the command line.
+///
+/// Surrounding whitespace and a leading `@` are dropped, and a profile URL
+/// such as `https://github.com/octocat` is reduced to its login. The result
+/// must follow GitHub's rules: up to 39 ASCII letters, digits and single
+/// hyphens, neither starting nor ending with a hyphen.
+pub fn normalize_username(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    let login = profile_login(trimmed).unwrap_or(trimmed);
+    let login = login.strip_prefix('@').unwrap_or(login);
+
+    if login.is_empty() {
+        return Err(format!("invalid username '{}': it is empty", input));
+    }
+    if login.len() > MAX_LOGIN_LEN {
+        return Err(format!(
+            "invalid username '{}': it is longer than {} characters",
+            login, MAX_LOGIN_LEN
+        ));
+    }
+    if let Some(c) = login.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '-') {
+        return Err(format!("invalid username '{}': '{}' is not allowed", login, c));
+    }
+    if login.starts_with('-') || login.ends_with('-') || login.contains("--") {
+        return Err(format!(
+            "invalid username '{}': hyphens must separate letters or digits",
+            login
+        ));
+    }
+    Ok(login.to_string())
+}
+
+/// Extracts the first path segment of a profile URL, with or without a scheme.
+fn profile_login(input: &str) -> Option<&str> {
+    let rest = match input.split_once("://") {
+        Some((_, rest)) => rest,
+        None if input.starts_with("github.com/") || input.starts_with("www.github.com/") => input,
+        None => return None,
+    };
+    let (_host, path) = rest.split_once('/')?;
+    path.split(['/', '?', '#']).find(|segment| !segment.is_empty())
+}
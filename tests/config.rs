@@ -0,0 +1,30 @@
+use github_activity::config::{Config, ConfigError};
+use std::fs;
+use std::path::PathBuf;
+
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("github-activity-{}-{}.toml", name, std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn loads_known_keys_and_collects_unknown_ones() {
+    let path = write_config("known", "timezone = \"Europe/Berlin\"\npages = 3\nabsolute-time = true\ncolour = \"never\"\n");
+
+    let config = Config::load(&path, false).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(config.timezone.as_deref(), Some("Europe/Berlin"));
+    assert_eq!(config.pages, Some(3));
+    assert_eq!(config.absolute_time, Some(true));
+    assert_eq!(config.unknown.keys().collect::<Vec<_>>(), ["colour"]);
+}
+
+#[test]
+fn missing_file_is_only_an_error_when_required() {
+    let path = std::env::temp_dir().join("github-activity-does-not-exist.toml");
+
+    assert!(Config::load(&path, true).unwrap().token.is_none());
+    assert!(matches!(Config::load(&path, false), Err(ConfigError::Read(..))));
+}
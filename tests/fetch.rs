@@ -0,0 +1,671 @@
+use github_activity::{
+    account_status, authenticated_user, fetch_activity, fetch_user_activity, format_activity, token_owner,
+    AccountStatus, ActivityError, Cache, FetchOptions, Timing,
+};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn event(id: u32, event_type: &str, repo: &str, payload: Value) -> Value {
+    json!({
+        "id": id.to_string(),
+        "type": event_type,
+        "actor": { "login": "octocat" },
+        "repo": { "name": repo },
+        "payload": payload,
+        "created_at": "2024-01-01T12:00:00Z"
+    })
+}
+
+fn options(server: &MockServer) -> FetchOptions {
+    FetchOptions {
+        base_url: server.uri(),
+        retries: 0,
+        ..FetchOptions::default()
+    }
+}
+
+async fn serve(server: &MockServer, status: u16, body: Value) {
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn parses_and_formats_every_event_type() {
+    let cases = [
+        (
+            event(1, "PushEvent", "o/r", json!({ "commits": [{ "sha": "a" }, { "sha": "b" }] })),
+            "Pushed 2 commits to o/r",
+        ),
+        (
+            event(2, "CreateEvent", "o/r", json!({ "ref_type": "branch", "ref": "dev" })),
+            "Created branch 'dev' in o/r",
+        ),
+        (
+            event(3, "DeleteEvent", "o/r", json!({ "ref_type": "tag", "ref": "v1" })),
+            "Deleted tag 'v1' in o/r",
+        ),
+        (
+            event(4, "IssuesEvent", "o/r", json!({ "action": "opened", "issue": { "number": 3, "title": "Bug" } })),
+            "Opened issue #3: Bug in o/r",
+        ),
+        (
+            event(5, "PullRequestEvent", "o/r", json!({ "action": "opened", "number": 7, "pull_request": { "title": "Fix" } })),
+            "Opened pull request #7: Fix in o/r",
+        ),
+        (event(6, "WatchEvent", "o/r", json!({ "action": "started" })), "Starred o/r"),
+        (
+            event(7, "ForkEvent", "o/r", json!({ "forkee": { "full_name": "octocat/r" } })),
+            "Forked o/r to octocat/r",
+        ),
+        (
+            event(8, "ReleaseEvent", "o/r", json!({ "action": "published", "release": { "tag_name": "v2.0" } })),
+            "Published release v2.0 in o/r",
+        ),
+        (event(9, "PublicEvent", "o/r", json!({})), "Made o/r public"),
+        (
+            event(10, "MemberEvent", "o/r", json!({ "action": "added", "member": { "login": "hubot" } })),
+            "Added hubot as collaborator to o/r",
+        ),
+        (
+            event(11, "IssueCommentEvent", "o/r", json!({ "action": "created", "issue": { "number": 4 } })),
+            "Created comment on issue #4 in o/r",
+        ),
+        (
+            event(12, "PullRequestReviewEvent", "o/r", json!({ "action": "created", "pull_request": { "number": 5 } })),
+            "Created review on pull request #5 in o/r",
+        ),
+        (
+            event(13, "PullRequestReviewCommentEvent", "o/r", json!({ "pull_request": { "number": 5 }, "comment": { "path": "src/lib.rs" } })),
+            "Commented on pull request #5 (src/lib.rs) in o/r",
+        ),
+        (
+            event(14, "CommitCommentEvent", "o/r", json!({ "comment": { "commit_id": "0123456789abcdef" } })),
+            "Commented on commit 0123456 in o/r",
+        ),
+        (
+            event(15, "GollumEvent", "o/r", json!({ "pages": [{ "action": "created", "title": "Home" }] })),
+            "Created wiki page 'Home' in o/r",
+        ),
+        (
+            event(16, "SponsorshipEvent", "o/r", json!({ "action": "created", "sponsorship": { "sponsorable": { "login": "hubot" } } })),
+            "Sponsored hubot",
+        ),
+        (
+            event(17, "StatusEvent", "o/r", json!({ "state": "success", "context": "ci/build", "sha": "89abcdef0123" })),
+            "Reported success status (ci/build) for commit 89abcde in o/r",
+        ),
+        (event(18, "TeamAddEvent", "o/r", json!({})), "Performed TeamAddEvent in o/r"),
+    ];
+
+    let server = MockServer::start().await;
+    let body: Vec<Value> = cases.iter().map(|(event, _)| event.clone()).collect();
+    serve(&server, 200, Value::Array(body)).await;
+
+    let events = fetch_user_activity("octocat", &options(&server)).await.unwrap();
+
+    assert_eq!(events.len(), cases.len());
+    for (event, (raw, expected)) in events.iter().zip(&cases) {
+        assert_eq!(event.id, raw["id"].as_str().unwrap());
+        assert_eq!(event.event_type, raw["type"].as_str().unwrap());
+        assert_eq!(event.actor.login, "octocat");
+        assert_eq!(event.repo.name, "o/r");
+        assert_eq!(format_activity(event), *expected);
+    }
+}
+
+#[tokio::test]
+async fn requests_the_first_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(query_param("per_page", "100"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let events = fetch_user_activity("octocat", &options(&server)).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn events_repeated_across_pages_are_dropped() {
+    let server = MockServer::start().await;
+    // A new event pushed the last one of page 1 onto page 2 mid-fetch.
+    let first: Vec<Value> = (1..=100).map(|id| event(id, "WatchEvent", "o/r", json!({}))).collect();
+    let second = vec![event(100, "WatchEvent", "o/r", json!({})), event(101, "WatchEvent", "o/r", json!({}))];
+    for (page, body) in [("1", first), ("2", second)] {
+        Mock::given(method("GET"))
+            .and(path("/users/octocat/events"))
+            .and(query_param("page", page))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+    }
+    let options = FetchOptions {
+        pages: 2,
+        ..options(&server)
+    };
+
+    let activity = fetch_activity("octocat", &options).await.unwrap();
+
+    let ids: Vec<&str> = activity.events.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids.len(), 101);
+    assert_eq!(ids[99..], ["100", "101"]);
+    assert_eq!(activity.duplicates, 1);
+}
+
+#[tokio::test]
+async fn uses_the_injected_client() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(header("x-client", "injected"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-client", "injected".parse().unwrap());
+    let client = reqwest::Client::builder().default_headers(headers).build().unwrap();
+    let options = FetchOptions {
+        client: Some(client),
+        ..options(&server)
+    };
+
+    fetch_user_activity("octocat", &options).await.unwrap();
+    fetch_user_activity("octocat", &options).await.unwrap();
+}
+
+#[tokio::test]
+async fn sends_the_configured_user_agent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(header("user-agent", "acme-dashboard/2.1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = FetchOptions {
+        user_agent: "acme-dashboard/2.1".to_string(),
+        ..options(&server)
+    };
+
+    fetch_user_activity("octocat", &options).await.unwrap();
+}
+
+#[tokio::test]
+async fn looks_up_the_token_owner() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "login": "octocat", "id": 1 })))
+        .mount(&server)
+        .await;
+    let options = FetchOptions {
+        token: Some("secret".to_string()),
+        ..options(&server)
+    };
+
+    assert_eq!(authenticated_user(&options).await.unwrap(), "octocat");
+}
+
+#[tokio::test]
+async fn token_owner_reports_classic_token_scopes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-oauth-scopes", "read:user, public_repo")
+                .set_body_json(json!({ "login": "octocat" })),
+        )
+        .mount(&server)
+        .await;
+
+    let owner = token_owner(&options(&server)).await.unwrap();
+
+    assert_eq!(owner.scopes, Some(vec!["read:user".to_string(), "public_repo".to_string()]));
+    assert!(!owner.can_read_private_repos());
+}
+
+#[tokio::test]
+async fn account_status_tells_quiet_accounts_from_missing_ones() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/quiet"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "login": "quiet" })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/banned"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "login": "banned", "suspended_at": "2024-01-01T12:00:00Z" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/ghost"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "login": "ghost" })))
+        .mount(&server)
+        .await;
+    let options = options(&server);
+
+    assert_eq!(account_status("quiet", &options).await.unwrap(), AccountStatus::Active);
+    assert_eq!(account_status("banned", &options).await.unwrap(), AccountStatus::Suspended);
+    assert_eq!(account_status("ghost", &options).await.unwrap(), AccountStatus::Ghost);
+    assert_eq!(account_status("gone", &options).await.unwrap(), AccountStatus::Unavailable);
+}
+
+#[tokio::test]
+async fn page_beyond_the_api_limit_ends_pagination() {
+    let server = MockServer::start().await;
+    let events: Vec<Value> = (0..100).map(|i| event(i, "WatchEvent", "o/r", json!({}))).collect();
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(events))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({ "message": "pagination is limited for this resource" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let options = FetchOptions {
+        pages: 3,
+        ..options(&server)
+    };
+
+    let events = fetch_user_activity("octocat", &options).await.unwrap();
+
+    assert_eq!(events.len(), 100);
+}
+
+#[tokio::test]
+async fn fetches_only_the_requested_page_range() {
+    let server = MockServer::start().await;
+    for page in 2..=3u32 {
+        let events: Vec<Value> = (0..100).map(|i| event(page * 100 + i, "WatchEvent", "o/r", json!({}))).collect();
+        Mock::given(method("GET"))
+            .and(path("/users/octocat/events"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(events))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    let options = FetchOptions {
+        first_page: 2,
+        pages: 2,
+        ..options(&server)
+    };
+
+    let events = fetch_user_activity("octocat", &options).await.unwrap();
+
+    assert_eq!(events.len(), 200);
+    assert_eq!(events[0].id, "200");
+    assert_eq!(events[199].id, "399");
+}
+
+#[test]
+fn debug_output_redacts_the_token() {
+    let options = FetchOptions {
+        token: Some("ghp_secret".to_string()),
+        ..FetchOptions::default()
+    };
+
+    let debug = format!("{:?}", options);
+
+    assert!(!debug.contains("ghp_secret"));
+    assert!(debug.contains("<redacted>"));
+}
+
+#[tokio::test]
+async fn private_events_are_flagged() {
+    let server = MockServer::start().await;
+    let mut private = event(2, "PushEvent", "o/secret", json!({}));
+    private["public"] = json!(false);
+    serve(&server, 200, json!([event(1, "WatchEvent", "o/r", json!({})), private])).await;
+
+    let events = fetch_user_activity("octocat", &options(&server)).await.unwrap();
+
+    assert!(events[0].public);
+    assert!(!events[1].public);
+}
+
+#[tokio::test]
+async fn raw_events_are_kept_as_sent() {
+    let server = MockServer::start().await;
+    let mut raw = event(1, "WatchEvent", "o/r", json!({ "action": "started" }));
+    raw["org"] = json!({ "login": "acme" });
+    serve(&server, 200, json!([raw])).await;
+    let options = FetchOptions {
+        raw: true,
+        ..options(&server)
+    };
+
+    let activity = fetch_activity("octocat", &options).await.unwrap();
+
+    assert_eq!(activity.events.len(), 1);
+    let kept: Value = serde_json::from_str(activity.raw[0].get()).unwrap();
+    assert_eq!(kept, raw);
+}
+
+#[tokio::test]
+async fn raw_events_leave_out_private_events_unless_asked() {
+    let server = MockServer::start().await;
+    let mut private = event(2, "PushEvent", "o/secret", json!({}));
+    private["public"] = json!(false);
+    serve(&server, 200, json!([event(1, "WatchEvent", "o/r", json!({})), private])).await;
+    let options = FetchOptions {
+        raw: true,
+        ..options(&server)
+    };
+
+    let activity = fetch_activity("octocat", &options).await.unwrap();
+
+    let public = activity.raw_events(false);
+    assert_eq!(public.len(), 1);
+    assert!(!public[0].get().contains("o/secret"));
+    assert_eq!(activity.raw_events(true).len(), 2);
+}
+
+#[tokio::test]
+async fn renamed_user_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(301).insert_header("location", "/users/hubot/events?per_page=100&page=1"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/hubot/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([event(1, "WatchEvent", "o/r", json!({}))])))
+        .mount(&server)
+        .await;
+
+    let activity = fetch_activity("octocat", &options(&server)).await.unwrap();
+
+    assert_eq!(activity.events.len(), 1);
+    assert_eq!(activity.renamed_to.as_deref(), Some("hubot"));
+}
+
+#[tokio::test]
+async fn unknown_user_is_not_found() {
+    let server = MockServer::start().await;
+    serve(&server, 404, json!({ "message": "Not Found" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::UserNotFound(ref user) if user == "octocat"));
+}
+
+#[tokio::test]
+async fn unknown_user_is_remembered_in_the_cache() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "message": "Not Found" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let dir = std::env::temp_dir().join(format!("github-activity-test-{}", std::process::id()));
+    let options = FetchOptions {
+        cache: Some(Cache::new(&dir, Duration::from_secs(300))),
+        ..options(&server)
+    };
+
+    let first = fetch_user_activity("octocat", &options).await.unwrap_err();
+    let second = fetch_user_activity("octocat", &options).await.unwrap_err();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(matches!(first, ActivityError::UserNotFound(_)));
+    assert!(matches!(second, ActivityError::UserNotFound(_)));
+}
+
+#[tokio::test]
+async fn exhausted_rate_limit_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("x-ratelimit-remaining", "0")
+                .insert_header("x-ratelimit-reset", "1700000000"),
+        )
+        .mount(&server)
+        .await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    match error {
+        ActivityError::RateLimited { reset } => {
+            assert_eq!(reset.map(|reset| reset.timestamp()), Some(1_700_000_000));
+        }
+        other => panic!("expected RateLimited, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn secondary_rate_limit_is_retried_after_the_requested_delay() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(403).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    serve(&server, 200, json!([event(1, "WatchEvent", "o/r", json!({}))])).await;
+    let options = FetchOptions {
+        retries: 1,
+        ..options(&server)
+    };
+
+    let events = fetch_user_activity("octocat", &options).await.unwrap();
+
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn timing_is_reported_for_every_attempt() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(403).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    serve(&server, 200, json!([])).await;
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let recorded = urls.clone();
+    let options = FetchOptions {
+        retries: 1,
+        timing: Some(Timing::new(move |url, _| recorded.lock().unwrap().push(url.to_string()))),
+        ..options(&server)
+    };
+
+    fetch_user_activity("octocat", &options).await.unwrap();
+
+    let expected = format!("{}/users/octocat/events?per_page=100&page=1", server.uri());
+    assert_eq!(*urls.lock().unwrap(), vec![expected.clone(), expected]);
+}
+
+#[tokio::test]
+async fn too_many_requests_without_retry_after_is_a_secondary_rate_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&server)
+        .await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(60)));
+    assert!(error.is_rate_limit());
+    assert!(error.is_transient());
+}
+
+#[tokio::test]
+async fn secondary_rate_limit_fails_once_retries_are_exhausted() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(403).insert_header("retry-after", "7"))
+        .mount(&server)
+        .await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(7)));
+}
+
+#[tokio::test]
+async fn forbidden_without_rate_limit_headers() {
+    let server = MockServer::start().await;
+    serve(&server, 403, json!({ "message": "Forbidden" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::Forbidden));
+}
+
+#[tokio::test]
+async fn rejected_token_is_unauthorized() {
+    let server = MockServer::start().await;
+    serve(&server, 401, json!({ "message": "Bad credentials" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::Unauthorized));
+}
+
+#[tokio::test]
+async fn unprocessable_request_includes_the_api_message() {
+    let server = MockServer::start().await;
+    serve(&server, 422, json!({ "message": "pagination is limited for this resource" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "GitHub rejected the request as invalid (422 Unprocessable Entity): pagination is limited for this resource"
+    );
+}
+
+#[tokio::test]
+async fn legal_block_names_the_user() {
+    let server = MockServer::start().await;
+    serve(&server, 451, json!({ "message": "Unavailable For Legal Reasons" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::UnavailableForLegalReasons(ref user) if user == "octocat"));
+}
+
+#[tokio::test]
+async fn malformed_json_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[{\"id\": ]"))
+        .mount(&server)
+        .await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::MalformedResponse { ref snippet, .. } if snippet == "[{\"id\": ]"));
+    assert!(!error.is_transient());
+}
+
+#[tokio::test]
+async fn truncated_body_is_retried() {
+    let server = MockServer::start().await;
+    let body = json!([event(1, "WatchEvent", "o/r", json!({}))]).to_string();
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(&body[..body.len() / 2]))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    serve(&server, 200, json!([event(1, "WatchEvent", "o/r", json!({}))])).await;
+    let options = FetchOptions {
+        retries: 1,
+        ..options(&server)
+    };
+
+    let events = fetch_user_activity("octocat", &options).await.unwrap();
+
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn truncated_body_fails_once_retries_are_exhausted() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[{\"id\": \"1\", \"type\": \"Wat"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(matches!(error, ActivityError::IncompleteResponse { received: 25, .. }));
+    assert!(error.is_transient());
+}
+
+#[tokio::test]
+async fn error_object_with_ok_status_is_malformed() {
+    let server = MockServer::start().await;
+    serve(&server, 200, json!({ "message": "Something changed" })).await;
+
+    let error = fetch_user_activity("octocat", &options(&server)).await.unwrap_err();
+
+    assert!(error.to_string().contains("{\"message\":\"Something changed\"}"));
+}
+
+#[tokio::test]
+async fn empty_body_is_no_activity() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let events = fetch_user_activity("octocat", &options(&server)).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn no_content_is_no_activity() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let events = fetch_user_activity("octocat", &options(&server)).await.unwrap();
+
+    assert!(events.is_empty());
+}
@@ -0,0 +1,141 @@
+use chrono::{TimeZone, Utc};
+use github_activity::{commit_shas, event_url, format_activity, Actor, GitHubEvent, Repository};
+use serde_json::{json, Value};
+
+fn event(event_type: &str, payload: Value) -> GitHubEvent {
+    GitHubEvent {
+        id: "1".to_string(),
+        event_type: event_type.to_string(),
+        actor: Actor { login: "octocat".to_string() },
+        repo: Repository { name: "o/r".to_string() },
+        payload,
+        created_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+        public: true,
+    }
+}
+
+#[test]
+fn event_url_prefers_the_payload_html_url() {
+    let event = event(
+        "PullRequestEvent",
+        json!({ "pull_request": { "html_url": "https://github.com/o/r/pull/7" } }),
+    );
+
+    assert_eq!(event_url(&event, "https://github.com").as_deref(), Some("https://github.com/o/r/pull/7"));
+}
+
+#[test]
+fn event_url_links_pushes_to_the_compared_commits() {
+    let event = event(
+        "PushEvent",
+        json!({ "before": "1111111111111111", "head": "2222222222222222" }),
+    );
+
+    assert_eq!(
+        event_url(&event, "https://github.com").as_deref(),
+        Some("https://github.com/o/r/compare/111111111111...222222222222")
+    );
+}
+
+#[test]
+fn event_url_falls_back_to_the_repository() {
+    let event = event("WatchEvent", json!({ "action": "started" }));
+
+    assert_eq!(event_url(&event, "https://github.example.com/").as_deref(), Some("https://github.example.com/o/r"));
+}
+
+#[test]
+fn member_event_names_the_member() {
+    let edited = event("MemberEvent", json!({ "action": "edited", "member": { "login": "hubot" } }));
+    let missing = event("MemberEvent", json!({ "action": "added" }));
+
+    assert_eq!(format_activity(&edited), "Edited permissions of hubot in o/r");
+    assert_eq!(format_activity(&missing), "Added a collaborator to o/r");
+}
+
+#[test]
+fn push_event_names_the_branch_and_flags_force_pushes() {
+    let push = event("PushEvent", json!({ "ref": "refs/heads/main", "commits": [{}, {}] }));
+    let forced = event("PushEvent", json!({ "ref": "refs/heads/dev", "forced": true, "commits": [{}] }));
+    let rewritten = event("PushEvent", json!({ "size": 2, "distinct_size": 0, "commits": [{}, {}] }));
+
+    assert_eq!(format_activity(&push), "Pushed 2 commits to main in o/r");
+    assert_eq!(format_activity(&forced), "Force-pushed 1 commit to dev in o/r");
+    assert_eq!(format_activity(&rewritten), "Force-pushed 2 commits to o/r");
+}
+
+#[test]
+fn push_event_omits_refs_that_are_not_branches() {
+    let tag = event("PushEvent", json!({ "ref": "refs/tags/v1.0", "commits": [{}] }));
+    let missing = event("PushEvent", json!({ "commits": [{}] }));
+
+    assert_eq!(format_activity(&tag), "Pushed 1 commit to o/r");
+    assert_eq!(format_activity(&missing), "Pushed 1 commit to o/r");
+}
+
+#[test]
+fn fork_event_without_forkee_keeps_the_short_message() {
+    assert_eq!(format_activity(&event("ForkEvent", json!({}))), "Forked o/r");
+}
+
+#[test]
+fn unknown_event_includes_the_payload_action() {
+    let labeled = event("DiscussionLabelEvent", json!({ "action": "labeled" }));
+    let long = event("FutureEvent", json!({ "action": "x".repeat(50) }));
+    let blank = event("FutureEvent", json!({ "action": " " }));
+
+    assert_eq!(format_activity(&labeled), "Performed DiscussionLabelEvent (labeled) in o/r");
+    assert_eq!(format_activity(&long), format!("Performed FutureEvent ({}...) in o/r", "x".repeat(37)));
+    assert_eq!(format_activity(&blank), "Performed FutureEvent in o/r");
+}
+
+#[test]
+fn closed_pull_request_says_whether_it_was_merged() {
+    let merged = event(
+        "PullRequestEvent",
+        json!({ "action": "closed", "number": 42, "pull_request": { "merged": true } }),
+    );
+    let merged_at = event(
+        "PullRequestEvent",
+        json!({ "action": "closed", "number": 42, "pull_request": { "merged_at": "2024-01-01T12:00:00Z" } }),
+    );
+    let closed = event(
+        "PullRequestEvent",
+        json!({ "action": "closed", "number": 42, "pull_request": { "merged": false, "merged_at": null } }),
+    );
+
+    assert_eq!(format_activity(&merged), "Merged pull request #42 in o/r");
+    assert_eq!(format_activity(&merged_at), "Merged pull request #42 in o/r");
+    assert_eq!(format_activity(&closed), "Closed pull request #42 in o/r");
+}
+
+#[test]
+fn release_event_shows_the_name_flags_and_assets() {
+    let full = event(
+        "ReleaseEvent",
+        json!({
+            "action": "published",
+            "release": { "tag_name": "v1.2.0", "name": "Spring update", "prerelease": true, "assets": [{}, {}, {}] }
+        }),
+    );
+    let plain = event(
+        "ReleaseEvent",
+        json!({ "action": "published", "release": { "tag_name": "v1.2.0", "name": "v1.2.0", "assets": [] } }),
+    );
+
+    assert_eq!(format_activity(&full), "Published release v1.2.0 'Spring update' (prerelease, 3 assets) in o/r");
+    assert_eq!(format_activity(&plain), "Published release v1.2.0 in o/r");
+}
+
+#[test]
+fn commit_shas_lists_every_push_commit_in_full() {
+    let commits: Vec<Value> = (0..12).map(|i| json!({ "sha": format!("{:040x}", i) })).collect();
+    let push = event("PushEvent", json!({ "commits": commits }));
+    let watch = event("WatchEvent", json!({ "commits": [{ "sha": "abc" }] }));
+
+    let shas = commit_shas(&push);
+
+    assert_eq!(shas.len(), 12);
+    assert_eq!(shas[11], format!("{:040x}", 11));
+    assert!(commit_shas(&watch).is_empty());
+}
@@ -0,0 +1,42 @@
+use chrono::{TimeZone, Utc};
+use chrono_tz::America::New_York;
+use github_activity::group::{format_week, group_by_week};
+use github_activity::time::Zone;
+use github_activity::{Actor, GitHubEvent, Repository};
+use serde_json::json;
+
+fn event_at(id: &str, day: u32, hour: u32) -> GitHubEvent {
+    GitHubEvent {
+        id: id.to_string(),
+        event_type: "PushEvent".to_string(),
+        actor: Actor { login: "octocat".to_string() },
+        repo: Repository { name: "o/r".to_string() },
+        payload: json!({}),
+        created_at: Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap(),
+        public: true,
+    }
+}
+
+#[test]
+fn group_by_week_puts_the_newest_week_first() {
+    // 2024-01-01 is the Monday of 2024-W01; the 15th starts W03.
+    let events = [event_at("a", 2, 12), event_at("b", 15, 12), event_at("c", 3, 12)];
+
+    let groups = group_by_week(&events, Zone::Utc);
+
+    let weeks: Vec<(String, Vec<&str>)> = groups
+        .iter()
+        .map(|(week, group)| (format_week(*week), group.iter().map(|e| e.id.as_str()).collect()))
+        .collect();
+    assert_eq!(weeks, [("2024-W03".to_string(), vec!["b"]), ("2024-W01".to_string(), vec!["a", "c"])]);
+}
+
+#[test]
+fn group_by_week_uses_the_zone_week() {
+    // 02:00 UTC on Monday the 8th is still Sunday evening in New York.
+    let events = [event_at("a", 8, 2)];
+
+    let groups = group_by_week(&events, Zone::Named(New_York));
+
+    assert_eq!(format_week(groups[0].0), "2024-W01");
+}
@@ -0,0 +1,34 @@
+use github_activity::paths::{create_private_dir, AppPaths};
+use std::fs;
+use std::path::PathBuf;
+
+#[test]
+fn config_file_lives_in_the_config_dir() {
+    let paths = AppPaths {
+        config_dir: Some(PathBuf::from("/home/me/.config/github-activity")),
+        cache_dir: None,
+    };
+
+    assert_eq!(paths.config_file(), Some(PathBuf::from("/home/me/.config/github-activity/config.toml")));
+    assert_eq!(AppPaths::default().config_file(), None);
+}
+
+#[test]
+fn creates_missing_parents_of_private_dirs() {
+    let root = std::env::temp_dir().join(format!("github-activity-paths-{}", std::process::id()));
+    let dir = root.join("nested/cache");
+
+    create_private_dir(&dir).unwrap();
+    create_private_dir(&dir).unwrap();
+    let metadata = fs::metadata(&dir).unwrap();
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode() & 0o777
+    };
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(metadata.is_dir());
+    #[cfg(unix)]
+    assert_eq!(mode, 0o700);
+}
@@ -0,0 +1,71 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use github_activity::stats::{activity_stats, count_by_day, total_commits};
+use github_activity::time::Zone;
+use github_activity::{Actor, GitHubEvent, Repository};
+use serde_json::json;
+
+fn event_at(year: i32, month: u32, day: u32, hour: u32) -> GitHubEvent {
+    GitHubEvent {
+        id: format!("{}-{}-{}-{}", year, month, day, hour),
+        event_type: "PushEvent".to_string(),
+        actor: Actor { login: "octocat".to_string() },
+        repo: Repository { name: "o/r".to_string() },
+        payload: json!({}),
+        created_at: Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap(),
+        public: true,
+    }
+}
+
+#[test]
+fn count_by_day_fills_every_day_of_the_range() {
+    let events = [event_at(2024, 1, 3, 12), event_at(2024, 1, 3, 13), event_at(2023, 12, 1, 0)];
+    let last = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+
+    let days = count_by_day(&events, Zone::Utc, last, 3);
+
+    let counts: Vec<usize> = days.iter().map(|(_, count)| *count).collect();
+    assert_eq!(days[0].0, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+    assert_eq!(counts, [0, 2, 0]);
+}
+
+#[test]
+fn count_by_day_uses_the_zone_day() {
+    // 03:00 UTC on the 4th is still the evening of the 3rd in New York.
+    let events = [event_at(2024, 1, 4, 3)];
+    let last = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+
+    let days = count_by_day(&events, Zone::Named(New_York), last, 2);
+
+    assert_eq!(days, [(NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(), 1), (last, 0)]);
+}
+
+#[test]
+fn activity_stats_sums_commits_and_counts_the_streak() {
+    let mut push = event_at(2024, 1, 4, 9);
+    push.payload = json!({ "size": 5 });
+    let events = [push, event_at(2024, 1, 3, 9), event_at(2024, 1, 3, 10), event_at(2024, 1, 1, 9)];
+    let today = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+
+    let stats = activity_stats(&events, Zone::Utc, today);
+
+    assert_eq!(stats.busiest_day, Some((NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(), 2)));
+    assert_eq!(stats.top_repo, Some(("o/r".to_string(), 4)));
+    // Three of the events are pushes without a size or commits.
+    assert_eq!(stats.commits, 5);
+    // Nothing yet on the 5th, so the streak runs back from the 4th.
+    assert_eq!(stats.streak, 2);
+}
+
+#[test]
+fn total_commits_prefers_size_over_the_capped_commits_array() {
+    let mut large = event_at(2024, 1, 1, 9);
+    large.payload = json!({ "size": 42, "commits": [{}, {}] });
+    let mut unsized_push = event_at(2024, 1, 1, 10);
+    unsized_push.payload = json!({ "commits": [{}, {}, {}] });
+    let mut issue = event_at(2024, 1, 1, 11);
+    issue.event_type = "IssuesEvent".to_string();
+    issue.payload = json!({ "size": 7 });
+
+    assert_eq!(total_commits(&[large, unsized_push, issue]), 45);
+}
@@ -0,0 +1,26 @@
+use github_activity::template::{Field, Template};
+
+fn render(template: &str) -> String {
+    Template::parse(template).unwrap().render(|field| match field {
+        Field::Type => "PushEvent".to_string(),
+        Field::Repo => "o/r".to_string(),
+        Field::Summary => "Pushed 1 commit to o/r".to_string(),
+        other => format!("<{:?}>", other),
+    })
+}
+
+#[test]
+fn substitutes_placeholders_and_escaped_braces() {
+    assert_eq!(render("{type}\t{repo}: {summary}"), "PushEvent\to/r: Pushed 1 commit to o/r");
+    assert_eq!(render("{{{actor}}} at {time}"), "{<Actor>} at <Time>");
+    assert_eq!(render("no placeholders"), "no placeholders");
+}
+
+#[test]
+fn rejects_unknown_placeholders_and_stray_braces() {
+    let error = Template::parse("{repo} {when}").unwrap_err();
+    assert!(error.contains("'{when}'"), "{}", error);
+
+    assert!(Template::parse("{repo").is_err());
+    assert!(Template::parse("repo}").is_err());
+}
@@ -0,0 +1,19 @@
+use chrono::TimeDelta;
+use github_activity::time::parse_age;
+
+#[test]
+fn parses_ages_with_a_unit() {
+    assert_eq!(parse_age("30m"), Ok(TimeDelta::minutes(30)));
+    assert_eq!(parse_age("6h"), Ok(TimeDelta::hours(6)));
+    assert_eq!(parse_age(" 7d "), Ok(TimeDelta::days(7)));
+    assert_eq!(parse_age("2w"), Ok(TimeDelta::weeks(2)));
+}
+
+#[test]
+fn rejects_ages_without_a_unit_or_count() {
+    assert!(parse_age("7").is_err());
+    assert!(parse_age("d").is_err());
+    assert!(parse_age("7y").is_err());
+    assert!(parse_age("-7d").is_err());
+    assert!(parse_age("0h").is_err());
+}
@@ -0,0 +1,19 @@
+use github_activity::username::normalize_username;
+
+#[test]
+fn strips_at_sign_whitespace_and_profile_urls() {
+    assert_eq!(normalize_username("  @octocat ").unwrap(), "octocat");
+    assert_eq!(normalize_username("https://github.com/octocat").unwrap(), "octocat");
+    assert_eq!(normalize_username("github.com/octo-cat/hello?tab=repos").unwrap(), "octo-cat");
+    assert_eq!(normalize_username("https://github.example.com/monalisa/").unwrap(), "monalisa");
+}
+
+#[test]
+fn rejects_clearly_invalid_names() {
+    assert!(normalize_username("").is_err());
+    assert!(normalize_username("octo_cat").is_err());
+    assert!(normalize_username("-octocat").is_err());
+    assert!(normalize_username("octo--cat").is_err());
+    assert!(normalize_username(&"a".repeat(40)).is_err());
+    assert!(normalize_username(&"a".repeat(39)).is_ok());
+}